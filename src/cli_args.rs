@@ -24,4 +24,7 @@ pub struct Challenge {
     pub day: u8,
     /// input file provided by advent of code
     pub input_file: PathBuf,
+    /// print statistics like cache hits while solving
+    #[arg(short, long)]
+    pub verbose: bool,
 }
@@ -11,6 +11,8 @@ pub mod year_2024;
 
 mod util;
 
+pub use util::memo::set_verbose;
+
 pub fn print_implemented() {
     print_implemented_year("2015", year_2015::get_implemented());
     print_implemented_year("2016", year_2016::get_implemented());
@@ -10,6 +10,7 @@ fn main() {
     match args.command {
         Commands::List => solutions::print_implemented(),
         Commands::Solve(challenge) => {
+            solutions::set_verbose(challenge.verbose);
             let path = challenge.input_file.as_path();
             let input = match fs::read_to_string(path) {
                 Ok(text) => text,
@@ -0,0 +1,49 @@
+mod signed;
+mod unsigned;
+
+pub use signed::BigInt;
+pub use unsigned::BigUint;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Empty,
+    InvalidDigit(char),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Empty => write!(f, "cannot parse an integer from an empty string"),
+            Error::InvalidDigit(c) => write!(f, "'{}' is not a decimal digit", c),
+        }
+    }
+}
+
+/// Implements an operator for owned operands and mixed ones on top of the implementation for
+/// two references.
+macro_rules! forward_binop_impl {
+    ($t:ty, $imp:ident, $method:ident) => {
+        impl std::ops::$imp<$t> for $t {
+            type Output = $t;
+            fn $method(self, rhs: $t) -> Self::Output {
+                std::ops::$imp::$method(&self, &rhs)
+            }
+        }
+
+        impl std::ops::$imp<&$t> for $t {
+            type Output = $t;
+            fn $method(self, rhs: &$t) -> Self::Output {
+                std::ops::$imp::$method(&self, rhs)
+            }
+        }
+
+        impl std::ops::$imp<$t> for &$t {
+            type Output = $t;
+            fn $method(self, rhs: $t) -> Self::Output {
+                std::ops::$imp::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+pub(crate) use forward_binop_impl;
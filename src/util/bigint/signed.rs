@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use super::{forward_binop_impl, BigUint, Error};
+
+/// Signed integer of arbitrary size. Division truncates towards zero like for the primitive
+/// integers, so the remainder takes the sign of the dividend.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BigInt {
+    /// Never set for zero, so there is just one representation of it.
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    fn new(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::new(false, self.magnitude.clone())
+    }
+
+    /// Quotient and remainder, `None` when dividing by zero.
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        let (quotient, remainder) = self.magnitude.div_rem(&divisor.magnitude)?;
+        Some((
+            Self::new(self.negative != divisor.negative, quotient),
+            Self::new(self.negative, remainder),
+        ))
+    }
+
+    pub fn pow(&self, exponent: u32) -> Self {
+        Self::new(
+            self.negative && exponent % 2 == 1,
+            self.magnitude.pow(exponent),
+        )
+    }
+
+    /// The value as `i128`, `None` if it does not fit.
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude.to_u128()?;
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(value: BigUint) -> Self {
+        Self::new(false, value)
+    }
+}
+
+macro_rules! bigint_from_impl {
+    (unsigned $unsigned:ty) => {
+        impl From<$unsigned> for BigInt {
+            fn from(value: $unsigned) -> Self {
+                Self::from(BigUint::from(value))
+            }
+        }
+    };
+    (signed $signed:ty) => {
+        impl From<$signed> for BigInt {
+            fn from(value: $signed) -> Self {
+                Self::new(value < 0, BigUint::from(value.unsigned_abs()))
+            }
+        }
+    };
+}
+
+bigint_from_impl!(unsigned u8);
+bigint_from_impl!(unsigned u16);
+bigint_from_impl!(unsigned u32);
+bigint_from_impl!(unsigned u64);
+bigint_from_impl!(unsigned u128);
+bigint_from_impl!(unsigned usize);
+bigint_from_impl!(signed i8);
+bigint_from_impl!(signed i16);
+bigint_from_impl!(signed i32);
+bigint_from_impl!(signed i64);
+bigint_from_impl!(signed i128);
+bigint_from_impl!(signed isize);
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> Self::Output {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> Self::Output {
+        BigInt::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::new(self.negative, &self.magnitude + &rhs.magnitude);
+        }
+        // opposite signs, so the larger magnitude wins
+        match self.magnitude.cmp(&rhs.magnitude) {
+            Ordering::Less => BigInt::new(rhs.negative, &rhs.magnitude - &self.magnitude),
+            _ => BigInt::new(self.negative, &self.magnitude - &rhs.magnitude),
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: &BigInt) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn mul(self, rhs: &BigInt) -> Self::Output {
+        BigInt::new(
+            self.negative != rhs.negative,
+            &self.magnitude * &rhs.magnitude,
+        )
+    }
+}
+
+/// Panics when dividing by zero.
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn div(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).expect("attempt to divide by zero").0
+    }
+}
+
+/// Panics when dividing by zero.
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn rem(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+            .1
+    }
+}
+
+forward_binop_impl!(BigInt, Add, add);
+forward_binop_impl!(BigInt, Sub, sub);
+forward_binop_impl!(BigInt, Mul, mul);
+forward_binop_impl!(BigInt, Div, div);
+forward_binop_impl!(BigInt, Rem, rem);
+
+impl std::iter::Sum for BigInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, value| acc + value)
+    }
+}
+
+/// Parses decimal digits with an optional leading `+` or `-`.
+impl std::str::FromStr for BigInt {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        Ok(Self::new(negative, digits.parse()?))
+    }
+}
+
+impl std::fmt::Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{BigInt, Error};
+
+    #[rstest]
+    #[case("0", "0")]
+    #[case("-0", "0")]
+    #[case("+15", "15")]
+    #[case("-98765432109876543210987654321", "-98765432109876543210987654321")]
+    fn display_round_trip(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(expected, text.parse::<BigInt>().unwrap().to_string());
+    }
+
+    #[rstest]
+    #[case("-", Err(Error::Empty))]
+    #[case("--1", Err(Error::InvalidDigit('-')))]
+    #[case("-12", Ok(BigInt::from(-12)))]
+    fn parse(#[case] text: &str, #[case] expected: Result<BigInt, Error>) {
+        assert_eq!(expected, text.parse::<BigInt>());
+    }
+
+    #[rstest]
+    #[case(7, 3)]
+    #[case(-7, 3)]
+    #[case(7, -3)]
+    #[case(-7, -3)]
+    #[case(0, -5)]
+    #[case(i64::MIN as i128, i64::MAX as i128)]
+    #[case(-123456789012345678, 987654321)]
+    fn matches_i128(#[case] a: i128, #[case] b: i128) {
+        let (big_a, big_b) = (BigInt::from(a), BigInt::from(b));
+        assert_eq!(Some(a + b), (&big_a + &big_b).to_i128());
+        assert_eq!(Some(a - b), (&big_a - &big_b).to_i128());
+        assert_eq!(Some(a * b), (&big_a * &big_b).to_i128());
+        assert_eq!(Some(a / b), (&big_a / &big_b).to_i128());
+        assert_eq!(Some(a % b), (&big_a % &big_b).to_i128());
+        assert_eq!(a.cmp(&b), big_a.cmp(&big_b));
+        assert_eq!(Some(-a), (-&big_a).to_i128());
+    }
+
+    #[test]
+    fn beyond_i128() {
+        let min = BigInt::from(i128::MIN);
+        assert_eq!(Some(i128::MIN), min.to_i128());
+        assert_eq!(None, (-&min).to_i128());
+        assert_eq!(None, (&min - BigInt::from(1)).to_i128());
+        let square = min.pow(2);
+        assert!(!square.is_negative());
+        assert_eq!(min, square / BigInt::from(i128::MIN));
+        assert_eq!(BigInt::from(-8), BigInt::from(-2).pow(3));
+        assert_eq!("  -42", format!("{:>5}", BigInt::from(-42)));
+    }
+}
@@ -0,0 +1,411 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use super::{forward_binop_impl, Error};
+
+/// Non-negative integer of arbitrary size.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BigUint {
+    /// Base 2^32 digits, least significant first and without trailing zeros, so zero has none.
+    limbs: Vec<u32>,
+}
+
+/// Largest power of ten fitting into a limb, used to parse and print nine digits at a time.
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self { limbs: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        Self { limbs: vec![1] }
+    }
+
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self { limbs }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Number of bits needed to represent the value, zero for zero.
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(last) => self.limbs.len() as u64 * 32 - last.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    fn bit(&self, index: u64) -> bool {
+        self.limbs
+            .get((index / 32) as usize)
+            .is_some_and(|limb| limb >> (index % 32) & 1 == 1)
+    }
+
+    /// Shifts left by one bit, shifting in `bit` at the bottom.
+    fn shift_in(&mut self, bit: bool) {
+        let mut carry = bit as u32;
+        for limb in self.limbs.iter_mut() {
+            let next_carry = *limb >> 31;
+            *limb = *limb << 1 | carry;
+            carry = next_carry;
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+    }
+
+    /// `self * factor + summand` in place.
+    fn mul_add_small(&mut self, factor: u32, summand: u32) {
+        let mut carry = summand as u64;
+        for limb in self.limbs.iter_mut() {
+            let value = *limb as u64 * factor as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    fn div_rem_small(&self, divisor: u32) -> (Self, u32) {
+        let mut quotient = vec![0u32; self.limbs.len()];
+        let mut remainder = 0u64;
+        for (i, limb) in self.limbs.iter().enumerate().rev() {
+            let value = remainder << 32 | *limb as u64;
+            quotient[i] = (value / divisor as u64) as u32;
+            remainder = value % divisor as u64;
+        }
+        (Self::from_limbs(quotient), remainder as u32)
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if *self < *rhs {
+            return None;
+        }
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for (i, limb) in self.limbs.iter().enumerate() {
+            let mut value = *limb as i64 - borrow - *rhs.limbs.get(i).unwrap_or(&0) as i64;
+            borrow = 0;
+            if value < 0 {
+                value += 1 << 32;
+                borrow = 1;
+            }
+            limbs.push(value as u32);
+        }
+        Some(Self::from_limbs(limbs))
+    }
+
+    /// Quotient and remainder, `None` when dividing by zero.
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        match divisor.limbs.as_slice() {
+            [] => None,
+            [small] => {
+                let (quotient, remainder) = self.div_rem_small(*small);
+                Some((quotient, Self::from(remainder)))
+            }
+            _ if self < divisor => Some((Self::zero(), self.clone())),
+            _ => {
+                // binary long division
+                let mut quotient = vec![0u32; self.limbs.len()];
+                let mut remainder = Self::zero();
+                for index in (0..self.bits()).rev() {
+                    remainder.shift_in(self.bit(index));
+                    if remainder >= *divisor {
+                        remainder = &remainder - divisor;
+                        quotient[(index / 32) as usize] |= 1 << (index % 32);
+                    }
+                }
+                Some((Self::from_limbs(quotient), remainder))
+            }
+        }
+    }
+
+    pub fn pow(&self, exponent: u32) -> Self {
+        let mut result = Self::one();
+        let mut base = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// The value as `u128`, `None` if it does not fit.
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0u128, |acc, limb| acc << 32 | *limb as u128),
+        )
+    }
+}
+
+macro_rules! biguint_from_impl {
+    ($unsigned:ty) => {
+        impl From<$unsigned> for BigUint {
+            fn from(value: $unsigned) -> Self {
+                let mut value = value as u128;
+                let mut limbs = Vec::new();
+                while value != 0 {
+                    limbs.push(value as u32);
+                    value >>= 32;
+                }
+                Self { limbs }
+            }
+        }
+    };
+}
+
+biguint_from_impl!(u8);
+biguint_from_impl!(u16);
+biguint_from_impl!(u32);
+biguint_from_impl!(u64);
+biguint_from_impl!(u128);
+biguint_from_impl!(usize);
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn add(self, rhs: &BigUint) -> Self::Output {
+        let (longer, shorter) = if self.limbs.len() >= rhs.limbs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut limbs = Vec::with_capacity(longer.limbs.len() + 1);
+        let mut carry = 0u64;
+        for (i, limb) in longer.limbs.iter().enumerate() {
+            let value = *limb as u64 + *shorter.limbs.get(i).unwrap_or(&0) as u64 + carry;
+            limbs.push(value as u32);
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+        BigUint { limbs }
+    }
+}
+
+/// Panics if the result would be negative, like the primitive unsigned integers in debug
+/// builds.
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn sub(self, rhs: &BigUint) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn mul(self, rhs: &BigUint) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::zero();
+        }
+        let mut limbs = vec![0u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, b) in rhs.limbs.iter().enumerate() {
+                let value = *a as u64 * *b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = value as u32;
+                carry = value >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+/// Panics when dividing by zero.
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn div(self, rhs: &BigUint) -> Self::Output {
+        self.div_rem(rhs).expect("attempt to divide by zero").0
+    }
+}
+
+/// Panics when dividing by zero.
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn rem(self, rhs: &BigUint) -> Self::Output {
+        self.div_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+            .1
+    }
+}
+
+forward_binop_impl!(BigUint, Add, add);
+forward_binop_impl!(BigUint, Sub, sub);
+forward_binop_impl!(BigUint, Mul, mul);
+forward_binop_impl!(BigUint, Div, div);
+forward_binop_impl!(BigUint, Rem, rem);
+
+impl std::iter::Sum for BigUint {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, value| acc + value)
+    }
+}
+
+/// Parses decimal digits without a sign.
+impl std::str::FromStr for BigUint {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+        if let Some(c) = s.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(Error::InvalidDigit(c));
+        }
+        let mut value = Self::zero();
+        let leading = s.len() % DECIMAL_CHUNK_DIGITS;
+        let chunks = std::iter::once(&s[..leading]).chain(
+            s.as_bytes()[leading..]
+                .chunks(DECIMAL_CHUNK_DIGITS)
+                .map(|chunk| std::str::from_utf8(chunk).unwrap()),
+        );
+        for chunk in chunks.filter(|chunk| !chunk.is_empty()) {
+            let factor = 10u32.pow(chunk.len() as u32);
+            value.mul_add_small(factor, chunk.parse().unwrap());
+        }
+        Ok(value)
+    }
+}
+
+impl std::fmt::Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, chunk) = rest.div_rem_small(DECIMAL_CHUNK);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+        let mut text = chunks.last().unwrap_or(&0).to_string();
+        for chunk in chunks.iter().rev().skip(1) {
+            text.push_str(&format!("{:0width$}", chunk, width = DECIMAL_CHUNK_DIGITS));
+        }
+        f.pad_integral(true, "", &text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{BigUint, Error};
+
+    fn big(text: &str) -> BigUint {
+        text.parse().unwrap()
+    }
+
+    #[rstest]
+    #[case("0")]
+    #[case("7")]
+    #[case("4294967296")]
+    #[case("1000000000")]
+    #[case("123456789012345678901234567890123456789012345678901234567890")]
+    fn display_round_trip(#[case] text: &str) {
+        assert_eq!(text, big(text).to_string());
+    }
+
+    #[rstest]
+    #[case("", Err(Error::Empty))]
+    #[case("12a", Err(Error::InvalidDigit('a')))]
+    #[case("-1", Err(Error::InvalidDigit('-')))]
+    #[case("007", Ok(BigUint::from(7u8)))]
+    fn parse(#[case] text: &str, #[case] expected: Result<BigUint, Error>) {
+        assert_eq!(expected, text.parse::<BigUint>());
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(u64::MAX as u128, 1)]
+    #[case(u128::MAX / 3, 2)]
+    #[case(123456789, 987654321)]
+    fn matches_u128(#[case] a: u128, #[case] b: u128) {
+        let (big_a, big_b) = (BigUint::from(a), BigUint::from(b));
+        assert_eq!(Some(a + b), (&big_a + &big_b).to_u128());
+        assert_eq!(
+            a.checked_sub(b),
+            big_a.checked_sub(&big_b).map(|d| d.to_u128().unwrap())
+        );
+        assert_eq!(a.checked_mul(b), (&big_a * &big_b).to_u128());
+        assert_eq!(a.cmp(&b), big_a.cmp(&big_b));
+        assert_eq!(
+            a.checked_div(b).zip(a.checked_rem(b)),
+            big_a
+                .div_rem(&big_b)
+                .map(|(q, r)| (q.to_u128().unwrap(), r.to_u128().unwrap()))
+        );
+    }
+
+    #[test]
+    fn beyond_u128() {
+        let power = BigUint::from(2u8).pow(200);
+        assert_eq!(
+            "1606938044258990275541962092341162602522202993782792835301376",
+            power.to_string()
+        );
+        assert_eq!(201, power.bits());
+        assert_eq!(None, power.to_u128());
+        let factorial = (1..=30u32)
+            .map(BigUint::from)
+            .fold(BigUint::one(), |a, b| a * b);
+        assert_eq!("265252859812191058636308480000000", factorial.to_string());
+        assert_eq!(
+            BigUint::from(2u8).pow(100),
+            &power / &BigUint::from(2u8).pow(100)
+        );
+    }
+
+    #[test]
+    fn long_division() {
+        let dividend = big("98765432109876543210987654321098765432109876543210");
+        let divisor = big("12345678901234567890123");
+        let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+        assert!(remainder < divisor);
+        assert_eq!(dividend, &quotient * &divisor + remainder);
+        assert_eq!(None, dividend.div_rem(&BigUint::zero()));
+    }
+
+    #[test]
+    fn sum_and_sub() {
+        let total = (0..100u32)
+            .map(|_| BigUint::from(u128::MAX))
+            .sum::<BigUint>();
+        assert_eq!(BigUint::from(u128::MAX) * BigUint::from(100u8), total);
+        assert_eq!(None, BigUint::one().checked_sub(&total));
+        assert_eq!(BigUint::zero(), &total - &total);
+        assert_eq!("   42", format!("{:>5}", BigUint::from(42u8)));
+    }
+}
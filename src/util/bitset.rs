@@ -0,0 +1,284 @@
+use super::position::Position;
+use super::size::Size;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Fixed number of bits packed into words. Indices beyond the length panic, like indexing a
+/// slice does.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// All `len` bits cleared.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    /// Number of bits, set or not.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn locate(&self, index: usize) -> (usize, u64) {
+        assert!(
+            index < self.len,
+            "bit index {} out of range for length {}",
+            index,
+            self.len
+        );
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        self.words[word] & mask != 0
+    }
+
+    /// Sets the bit, returning whether it was cleared before.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        let was_clear = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        was_clear
+    }
+
+    /// Clears the bit, returning whether it was set before.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_set
+    }
+
+    /// Clears all bits while keeping the allocation.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Number of set bits.
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Indices of the set bits in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &word)| SetBits { word }.map(move |bit| index * WORD_BITS + bit))
+    }
+
+    fn assert_same_len(&self, other: &Self) {
+        assert_eq!(self.len, other.len, "bit sets differ in length");
+    }
+
+    /// Sets every bit that is set in `other`, both have to be of the same length.
+    pub fn union_with(&mut self, other: &Self) {
+        self.assert_same_len(other);
+        self.words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(word, other)| *word |= other);
+    }
+
+    /// Clears every bit that is not set in `other`, both have to be of the same length.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.assert_same_len(other);
+        self.words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(word, other)| *word &= other);
+    }
+}
+
+/// Positions of the set bits of a single word, lowest first.
+struct SetBits {
+    word: u64,
+}
+
+impl Iterator for SetBits {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.word == 0 {
+            return None;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(bit)
+    }
+}
+
+/// One bit per cell of a grid, stored row by row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BitGrid {
+    size: Size,
+    bits: BitSet,
+}
+
+impl BitGrid {
+    /// All cells cleared.
+    pub fn new(size: Size) -> Self {
+        Self {
+            bits: BitSet::new(size.width * size.height),
+            size,
+        }
+    }
+
+    pub fn size(&self) -> &Size {
+        &self.size
+    }
+
+    fn index(&self, position: &Position) -> usize {
+        assert!(
+            position.is_within_size(&self.size),
+            "{:?} out of bounds for {:?}",
+            position,
+            self.size
+        );
+        position.y * self.size.width + position.x
+    }
+
+    /// `false` for positions outside of the grid, so neighbours can be tested without a bounds
+    /// check.
+    pub fn contains(&self, position: &Position) -> bool {
+        position.is_within_size(&self.size) && self.bits.contains(self.index(position))
+    }
+
+    /// Sets the cell, returning whether it was cleared before. Panics outside of the grid.
+    pub fn insert(&mut self, position: &Position) -> bool {
+        let index = self.index(position);
+        self.bits.insert(index)
+    }
+
+    /// Clears the cell, returning whether it was set before. Panics outside of the grid.
+    pub fn remove(&mut self, position: &Position) -> bool {
+        let index = self.index(position);
+        self.bits.remove(index)
+    }
+
+    /// Clears all cells while keeping the allocation.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// Number of set cells.
+    pub fn count(&self) -> usize {
+        self.bits.count()
+    }
+
+    /// Positions of the set cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = Position> + '_ {
+        self.bits.iter().map(|index| Position {
+            x: index % self.size.width,
+            y: index / self.size.width,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{BitGrid, BitSet, Position, Size};
+
+    #[test]
+    fn set_test_clear() {
+        let mut set = BitSet::new(130);
+        assert_eq!(130, set.len());
+        assert!(set.insert(0));
+        assert!(set.insert(64));
+        assert!(set.insert(129));
+        assert!(!set.insert(64));
+        assert!(set.contains(129));
+        assert!(!set.contains(128));
+        assert_eq!(3, set.count());
+        assert_eq!(vec![0, 64, 129], set.iter().collect::<Vec<_>>());
+        assert!(set.remove(64));
+        assert!(!set.remove(64));
+        assert_eq!(vec![0, 129], set.iter().collect::<Vec<_>>());
+        set.clear();
+        assert_eq!(0, set.count());
+        assert_eq!(BitSet::new(130), set);
+    }
+
+    #[test]
+    fn combine() {
+        let mut a = BitSet::new(70);
+        let mut b = BitSet::new(70);
+        [1, 5, 69].into_iter().for_each(|i| _ = a.insert(i));
+        [5, 6, 69].into_iter().for_each(|i| _ = b.insert(i));
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(vec![1, 5, 6, 69], union.iter().collect::<Vec<_>>());
+        a.intersect_with(&b);
+        assert_eq!(vec![5, 69], a.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range() {
+        BitSet::new(64).insert(64);
+    }
+
+    #[test]
+    fn empty() {
+        let set = BitSet::new(0);
+        assert!(set.is_empty());
+        assert_eq!(None, set.iter().next());
+    }
+
+    #[rstest]
+    #[case(Position { x: 0, y: 0 }, true)]
+    #[case(Position { x: 4, y: 2 }, true)]
+    #[case(Position { x: 5, y: 2 }, false)]
+    #[case(Position { x: 0, y: 3 }, false)]
+    fn grid_contains(#[case] position: Position, #[case] expected: bool) {
+        let mut grid = BitGrid::new(Size {
+            width: 5,
+            height: 3,
+        });
+        grid.insert(&Position { x: 0, y: 0 });
+        grid.insert(&Position { x: 4, y: 2 });
+        assert_eq!(expected, grid.contains(&position));
+    }
+
+    #[test]
+    fn grid_iteration() {
+        let mut grid = BitGrid::new(Size {
+            width: 3,
+            height: 4,
+        });
+        let positions = vec![
+            Position { x: 2, y: 0 },
+            Position { x: 0, y: 1 },
+            Position { x: 1, y: 3 },
+        ];
+        positions.iter().for_each(|p| _ = grid.insert(p));
+        assert_eq!(positions, grid.iter().collect::<Vec<_>>());
+        assert_eq!(3, grid.count());
+        assert!(grid.remove(&Position { x: 0, y: 1 }));
+        assert_eq!(2, grid.count());
+        grid.clear();
+        assert_eq!(0, grid.count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_insert_out_of_bounds() {
+        BitGrid::new(Size {
+            width: 3,
+            height: 3,
+        })
+        .insert(&Position { x: 3, y: 0 });
+    }
+}
@@ -0,0 +1,315 @@
+/// Every pair `(a, b)` of distinct positions with `a` before `b`, e.g. for comparing all
+/// antennas with one another exactly once.
+pub fn pairs<T>(items: &[T]) -> impl Iterator<Item = (&T, &T)> {
+    items
+        .iter()
+        .enumerate()
+        .flat_map(move |(i, a)| items[i + 1..].iter().map(move |b| (a, b)))
+}
+
+/// Every sequence of `length` items from `alphabet`, repetition allowed, in lexicographic order
+/// of the alphabet positions. E.g. the operators to put between the numbers of an equation.
+///
+/// Step through the sequences with `advance`, which lends out each one from an internal buffer
+/// without allocating. The `Iterator` impl is only a convenience for collecting, as it copies
+/// every sequence into a new `Vec`. The same holds for [`Combinations`] and [`Permutations`].
+pub struct CartesianPower<'a, T> {
+    alphabet: &'a [T],
+    indices: Vec<usize>,
+    current: Vec<T>,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T: Clone> CartesianPower<'a, T> {
+    pub fn new(alphabet: &'a [T], length: usize) -> Self {
+        Self {
+            alphabet,
+            indices: vec![0; length],
+            current: alphabet
+                .first()
+                .map_or(Vec::new(), |first| vec![first.clone(); length]),
+            started: false,
+            done: alphabet.is_empty() && length > 0,
+        }
+    }
+
+    /// Number of sequences in total, `None` if that does not fit into a `usize`.
+    pub fn sequence_count(&self) -> Option<usize> {
+        u32::try_from(self.indices.len())
+            .ok()
+            .and_then(|length| self.alphabet.len().checked_pow(length))
+    }
+
+    /// Next sequence, borrowed until the following call. `None` once all are done.
+    pub fn advance(&mut self) -> Option<&[T]> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(&self.current);
+        }
+        // odometer with the last position turning fastest
+        for position in (0..self.indices.len()).rev() {
+            self.indices[position] += 1;
+            if self.indices[position] < self.alphabet.len() {
+                self.current[position] = self.alphabet[self.indices[position]].clone();
+                return Some(&self.current);
+            }
+            self.indices[position] = 0;
+            self.current[position] = self.alphabet[0].clone();
+        }
+        self.done = true;
+        None
+    }
+}
+
+/// Allocates a `Vec` per item, prefer [`CartesianPower::advance`] where that matters.
+impl<T: Clone> Iterator for CartesianPower<'_, T> {
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance().map(<[T]>::to_vec)
+    }
+}
+
+/// Every selection of `k` items in their original order, the selections in lexicographic order
+/// of the positions.
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    current: Vec<T>,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T: Clone> Combinations<'a, T> {
+    pub fn new(items: &'a [T], k: usize) -> Self {
+        let done = k > items.len();
+        Self {
+            items,
+            indices: (0..k).collect(),
+            current: if done {
+                Vec::new()
+            } else {
+                items[..k].to_vec()
+            },
+            started: false,
+            done,
+        }
+    }
+
+    /// Next selection, borrowed until the following call. `None` once all are done.
+    pub fn advance(&mut self) -> Option<&[T]> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(&self.current);
+        }
+        let (n, k) = (self.items.len(), self.indices.len());
+        // the rightmost position that can still move to the right
+        let Some(position) = (0..k).rev().find(|i| self.indices[*i] < n - k + i) else {
+            self.done = true;
+            return None;
+        };
+        self.indices[position] += 1;
+        for i in position..k {
+            if i > position {
+                self.indices[i] = self.indices[i - 1] + 1;
+            }
+            self.current[i] = self.items[self.indices[i]].clone();
+        }
+        Some(&self.current)
+    }
+}
+
+/// Allocates a `Vec` per item, prefer [`Combinations::advance`] where that matters.
+impl<T: Clone> Iterator for Combinations<'_, T> {
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance().map(<[T]>::to_vec)
+    }
+}
+
+/// Every ordering of all items, in lexicographic order of the positions.
+pub struct Permutations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    current: Vec<T>,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T: Clone> Permutations<'a, T> {
+    pub fn new(items: &'a [T]) -> Self {
+        Self {
+            items,
+            indices: (0..items.len()).collect(),
+            current: items.to_vec(),
+            started: false,
+            done: false,
+        }
+    }
+
+    /// Next ordering, borrowed until the following call. `None` once all are done.
+    pub fn advance(&mut self) -> Option<&[T]> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(&self.current);
+        }
+        // next lexicographic permutation: find the last ascent, swap its start with the
+        // smallest larger index after it and reverse the tail
+        let Some(pivot) = (1..self.indices.len())
+            .rev()
+            .find(|i| self.indices[i - 1] < self.indices[*i])
+            .map(|i| i - 1)
+        else {
+            self.done = true;
+            return None;
+        };
+        let successor = (pivot + 1..self.indices.len())
+            .rev()
+            .find(|i| self.indices[*i] > self.indices[pivot])
+            .unwrap();
+        self.indices.swap(pivot, successor);
+        self.indices[pivot + 1..].reverse();
+        for i in pivot..self.indices.len() {
+            self.current[i] = self.items[self.indices[i]].clone();
+        }
+        Some(&self.current)
+    }
+}
+
+/// Allocates a `Vec` per item, prefer [`Permutations::advance`] where that matters.
+impl<T: Clone> Iterator for Permutations<'_, T> {
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance().map(<[T]>::to_vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{pairs, CartesianPower, Combinations, Permutations};
+
+    fn collect_cartesian_power(alphabet: &[char], length: usize) -> Vec<String> {
+        let mut power = CartesianPower::new(alphabet, length);
+        let mut result = Vec::new();
+        while let Some(sequence) = power.advance() {
+            result.push(sequence.iter().collect());
+        }
+        result
+    }
+
+    fn collect_combinations(items: &[char], k: usize) -> Vec<String> {
+        let mut combinations = Combinations::new(items, k);
+        let mut result = Vec::new();
+        while let Some(selection) = combinations.advance() {
+            result.push(selection.iter().collect());
+        }
+        result
+    }
+
+    fn collect_permutations(items: &[char]) -> Vec<String> {
+        let mut permutations = Permutations::new(items);
+        let mut result = Vec::new();
+        while let Some(ordering) = permutations.advance() {
+            result.push(ordering.iter().collect());
+        }
+        result
+    }
+
+    #[rstest]
+    #[case(&['a', 'b'], 2, vec!["aa", "ab", "ba", "bb"])]
+    #[case(&['+', '*', '|'], 1, vec!["+", "*", "|"])]
+    #[case(&['x'], 3, vec!["xxx"])]
+    #[case(&['a', 'b'], 0, vec![""])]
+    #[case(&[], 2, vec![])]
+    fn cartesian_power(
+        #[case] alphabet: &[char],
+        #[case] length: usize,
+        #[case] expected: Vec<&str>,
+    ) {
+        assert_eq!(expected, collect_cartesian_power(alphabet, length));
+        assert_eq!(
+            Some(expected.len()),
+            CartesianPower::new(alphabet, length).sequence_count()
+        );
+    }
+
+    #[test]
+    fn cartesian_power_count() {
+        assert_eq!(81, collect_cartesian_power(&['a', 'b', 'c'], 4).len());
+        assert_eq!(None, CartesianPower::new(&[0, 1], 64).sequence_count());
+    }
+
+    #[test]
+    fn iterators_match_advance() {
+        let items = ['a', 'b', 'c', 'd'];
+        let as_strings = |sequences: Vec<Vec<char>>| {
+            sequences
+                .into_iter()
+                .map(|s| s.into_iter().collect::<String>())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            collect_cartesian_power(&items, 3),
+            as_strings(CartesianPower::new(&items, 3).collect())
+        );
+        assert_eq!(
+            collect_combinations(&items, 2),
+            as_strings(Combinations::new(&items, 2).collect())
+        );
+        assert_eq!(
+            collect_permutations(&items),
+            as_strings(Permutations::new(&items).collect())
+        );
+        assert_eq!(1, Combinations::new(&items, 4).count());
+    }
+
+    #[rstest]
+    #[case(&['a', 'b', 'c', 'd'], 2, vec!["ab", "ac", "ad", "bc", "bd", "cd"])]
+    #[case(&['a', 'b', 'c'], 3, vec!["abc"])]
+    #[case(&['a', 'b', 'c'], 0, vec![""])]
+    #[case(&['a', 'b'], 3, vec![])]
+    fn combinations(#[case] items: &[char], #[case] k: usize, #[case] expected: Vec<&str>) {
+        assert_eq!(expected, collect_combinations(items, k));
+    }
+
+    #[test]
+    fn combination_count() {
+        let items = ('a'..='j').collect::<Vec<_>>();
+        assert_eq!(252, collect_combinations(&items, 5).len());
+    }
+
+    #[rstest]
+    #[case(&['a', 'b', 'c'], vec!["abc", "acb", "bac", "bca", "cab", "cba"])]
+    #[case(&['a', 'a'], vec!["aa", "aa"])]
+    #[case(&['z'], vec!["z"])]
+    #[case(&[], vec![""])]
+    fn permutations(#[case] items: &[char], #[case] expected: Vec<&str>) {
+        assert_eq!(expected, collect_permutations(items));
+    }
+
+    #[test]
+    fn permutation_count() {
+        assert_eq!(5040, collect_permutations(&['a'; 7]).len());
+    }
+
+    #[test]
+    fn all_pairs() {
+        assert_eq!(
+            vec![(&1, &2), (&1, &3), (&2, &3)],
+            pairs(&[1, 2, 3]).collect::<Vec<_>>()
+        );
+        assert_eq!(0, pairs(&[1]).count());
+        assert_eq!(0, pairs::<u8>(&[]).count());
+    }
+}
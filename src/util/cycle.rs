@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shape of the sequence `start, step(start), step(step(start)), ...` of a deterministic
+/// simulation: after `prefix_length` steps the states repeat every `length` steps.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Cycle {
+    pub prefix_length: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Smallest number of steps leading to the same state as `steps` steps.
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.prefix_length {
+            steps
+        } else {
+            self.prefix_length + (steps - self.prefix_length) % self.length
+        }
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, keeping only two states in memory. The sequence
+/// must eventually repeat, otherwise this never returns.
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1usize;
+    let mut length = 1usize;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut prefix_length = 0usize;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_length += 1;
+    }
+    Cycle {
+        prefix_length,
+        length,
+    }
+}
+
+/// Finds the cycle with Floyd's tortoise and hare algorithm, keeping only two states in
+/// memory. The sequence must eventually repeat, otherwise this never returns.
+pub fn floyd<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+    let mut prefix_length = 0usize;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_length += 1;
+    }
+    let mut length = 1usize;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle {
+        prefix_length,
+        length,
+    }
+}
+
+/// Finds the cycle by remembering every state seen. Takes more memory than [`brent`] but
+/// calls `step` only once per state, which pays off for expensive simulations.
+pub fn find_cycle<S: Clone + Eq + Hash>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut state = start;
+    loop {
+        let index = seen.len();
+        if let Some(first) = seen.insert(state.clone(), index) {
+            return Cycle {
+                prefix_length: first,
+                length: index - first,
+            };
+        }
+        state = step(&state);
+    }
+}
+
+/// State after applying `step` `steps` times, skipping ahead as soon as a repeated state
+/// reveals the cycle.
+pub fn state_after<S: Clone + Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    steps: usize,
+) -> S {
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = start;
+    while history.len() < steps {
+        if let Some(first) = seen.insert(state.clone(), history.len()) {
+            let cycle = Cycle {
+                prefix_length: first,
+                length: history.len() - first,
+            };
+            return history.swap_remove(cycle.reduce(steps));
+        }
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{brent, find_cycle, floyd, state_after, Cycle};
+
+    /// 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3 -> ...
+    fn rho(state: &u32) -> u32 {
+        if *state == 6 {
+            3
+        } else {
+            state + 1
+        }
+    }
+
+    fn lcg(state: &u64) -> u64 {
+        (state * 37 + 11) % 1000
+    }
+
+    #[rstest]
+    #[case(0, 3, 4)]
+    #[case(3, 0, 4)]
+    #[case(5, 0, 4)]
+    fn algorithms_agree(#[case] start: u32, #[case] prefix_length: usize, #[case] length: usize) {
+        let expected = Cycle {
+            prefix_length,
+            length,
+        };
+        assert_eq!(expected, brent(start, rho));
+        assert_eq!(expected, floyd(start, rho));
+        assert_eq!(expected, find_cycle(start, rho));
+    }
+
+    #[test]
+    fn algorithms_agree_on_generator() {
+        let expected = find_cycle(1, lcg);
+        assert_eq!(expected, brent(1, lcg));
+        assert_eq!(expected, floyd(1, lcg));
+    }
+
+    #[test]
+    fn fixed_point() {
+        let expected = Cycle {
+            prefix_length: 2,
+            length: 1,
+        };
+        let step = |s: &i32| (s + 1).min(2);
+        assert_eq!(expected, brent(0, step));
+        assert_eq!(expected, floyd(0, step));
+        assert_eq!(expected, find_cycle(0, step));
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(2, 2)]
+    #[case(7, 3)]
+    #[case(10, 6)]
+    #[case(1_000_000_000, 4)]
+    fn state_after_steps(#[case] steps: usize, #[case] expected: u32) {
+        assert_eq!(expected, state_after(0, rho, steps));
+    }
+
+    #[test]
+    fn state_after_matches_simulation() {
+        let mut state = 1;
+        for steps in 0..300 {
+            assert_eq!(state, state_after(1, lcg, steps));
+            state = lcg(&state);
+        }
+    }
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(3, 3)]
+    #[case(7, 3)]
+    #[case(1_000_000_000, 4)]
+    fn reduce(#[case] steps: usize, #[case] expected: usize) {
+        let cycle = Cycle {
+            prefix_length: 3,
+            length: 4,
+        };
+        assert_eq!(expected, cycle.reduce(steps));
+    }
+}
@@ -0,0 +1,180 @@
+use super::point::Vector;
+
+/// Compass direction on a grid where up means decreasing `y`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four orthogonal directions in clockwise order, starting upwards.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The four diagonal directions in clockwise order, starting up right.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+    ];
+
+    /// All eight directions in clockwise order, starting upwards.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    fn from_index(index: usize) -> Self {
+        Self::ALL[index % 8]
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self.index() + 2)
+    }
+
+    /// Rotates by 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self.index() + 6)
+    }
+
+    /// Rotates by 45 degrees clockwise.
+    pub fn turn_half_right(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Rotates by 45 degrees counterclockwise.
+    pub fn turn_half_left(self) -> Self {
+        Self::from_index(self.index() + 7)
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::from_index(self.index() + 4)
+    }
+
+    /// Unit step in this direction, diagonal ones moving along both axes.
+    pub fn to_vector<T: From<i8>>(self) -> Vector<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        };
+        Vector {
+            x: T::from(x),
+            y: T::from(y),
+        }
+    }
+}
+
+/// Parses the arrows `^>v<` and the letters `URDL` as well as `NESW`.
+impl TryFrom<char> for Direction {
+    type Error = char;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            c => Err(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Direction;
+    use super::Vector;
+
+    #[rstest]
+    #[case(Direction::Up, Direction::Right, Direction::Left, Direction::Down)]
+    #[case(Direction::Left, Direction::Up, Direction::Down, Direction::Right)]
+    #[case(
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::UpLeft,
+        Direction::DownLeft
+    )]
+    #[case(
+        Direction::DownLeft,
+        Direction::UpLeft,
+        Direction::DownRight,
+        Direction::UpRight
+    )]
+    fn turning(
+        #[case] direction: Direction,
+        #[case] right: Direction,
+        #[case] left: Direction,
+        #[case] opposite: Direction,
+    ) {
+        assert_eq!(right, direction.turn_right());
+        assert_eq!(left, direction.turn_left());
+        assert_eq!(opposite, direction.opposite());
+        assert_eq!(direction, direction.turn_half_right().turn_half_left());
+    }
+
+    #[test]
+    fn half_turns() {
+        assert_eq!(Direction::UpRight, Direction::Up.turn_half_right());
+        assert_eq!(Direction::UpLeft, Direction::Up.turn_half_left());
+        assert_eq!(Direction::Up, Direction::UpLeft.turn_half_right());
+    }
+
+    #[test]
+    fn vectors() {
+        for direction in Direction::ALL {
+            let vector = direction.to_vector::<i32>();
+            assert_eq!(-vector, direction.opposite().to_vector());
+            assert_eq!(direction.is_diagonal(), vector.x != 0 && vector.y != 0);
+        }
+        assert_eq!(Vector::new(0, -1), Direction::Up.to_vector::<i64>());
+        assert_eq!(
+            Vector::new(-1.0, 1.0),
+            Direction::DownLeft.to_vector::<f64>()
+        );
+    }
+
+    #[rstest]
+    #[case('^', Ok(Direction::Up))]
+    #[case('>', Ok(Direction::Right))]
+    #[case('v', Ok(Direction::Down))]
+    #[case('<', Ok(Direction::Left))]
+    #[case('L', Ok(Direction::Left))]
+    #[case('S', Ok(Direction::Down))]
+    #[case('x', Err('x'))]
+    fn parse(#[case] c: char, #[case] expected: Result<Direction, char>) {
+        assert_eq!(expected, Direction::try_from(c));
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Union-find over the dense ids `0..len`, using path compression and union by rank.
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    component_count: usize,
+}
+
+impl DisjointSet {
+    /// Creates `len` singleton components.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            component_count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Adds a new singleton component, returning its id.
+    pub fn add(&mut self) -> usize {
+        let id = self.parent.len();
+        self.parent.push(id);
+        self.rank.push(0);
+        self.size.push(1);
+        self.component_count += 1;
+        id
+    }
+
+    /// Representative of the component containing `id`. Panics if `id` is out of range.
+    pub fn find(&mut self, id: usize) -> usize {
+        let mut root = id;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // point everything on the way directly to the root
+        let mut current = id;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merges the components of `a` and `b`. Returns `false` if they already were one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (parent, child) = if self.rank[a] < self.rank[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        if self.rank[parent] == self.rank[child] {
+            self.rank[parent] += 1;
+        }
+        self.parent[child] = parent;
+        self.size[parent] += self.size[child];
+        self.component_count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of ids in the component containing `id`.
+    pub fn component_size(&mut self, id: usize) -> usize {
+        let root = self.find(id);
+        self.size[root]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// All components with their ids in ascending order, ordered by their smallest id.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index_of_root = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.component_count);
+        for id in 0..self.len() {
+            let root = self.find(id);
+            let index = *index_of_root.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[index].push(id);
+        }
+        components
+    }
+}
+
+/// Union-find over arbitrary keys, each key getting a dense id when first seen.
+#[derive(Clone, Debug)]
+pub struct KeyedDisjointSet<K> {
+    ids: HashMap<K, usize>,
+    keys: Vec<K>,
+    sets: DisjointSet,
+}
+
+impl<K: Clone + Eq + Hash> Default for KeyedDisjointSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Eq + Hash> KeyedDisjointSet<K> {
+    pub fn new() -> Self {
+        Self {
+            ids: HashMap::new(),
+            keys: Vec::new(),
+            sets: DisjointSet::new(0),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.ids.contains_key(key)
+    }
+
+    /// Adds `key` as a singleton component unless it is known already, returning its id.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(id) = self.ids.get(&key) {
+            return *id;
+        }
+        let id = self.sets.add();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    /// Representative key of the component containing `key`.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let id = *self.ids.get(key)?;
+        let root = self.sets.find(id);
+        Some(&self.keys[root])
+    }
+
+    /// Merges the components of `a` and `b`, inserting either if unknown. Returns `false` if
+    /// they already were one.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// Whether both keys are known and in the same component.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.ids.get(a), self.ids.get(b)) {
+            (Some(a), Some(b)) => self.sets.connected(*a, *b),
+            _ => false,
+        }
+    }
+
+    pub fn component_size(&mut self, key: &K) -> Option<usize> {
+        let id = *self.ids.get(key)?;
+        Some(self.sets.component_size(id))
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    /// All components with their keys in insertion order, ordered by their first inserted key.
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|ids| ids.into_iter().map(|id| self.keys[id].clone()).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{DisjointSet, KeyedDisjointSet};
+
+    #[test]
+    fn union_and_find() {
+        let mut sets = DisjointSet::new(6);
+        assert_eq!(6, sets.component_count());
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(3, sets.component_count());
+        assert_eq!(4, sets.component_size(2));
+        assert_eq!(1, sets.component_size(5));
+        assert_eq!(vec![vec![0, 1, 2, 3], vec![4], vec![5]], sets.components());
+        let id = sets.add();
+        assert_eq!(6, id);
+        sets.union(id, 4);
+        assert_eq!(
+            vec![vec![0, 1, 2, 3], vec![4, 6], vec![5]],
+            sets.components()
+        );
+    }
+
+    #[test]
+    fn long_chain_is_compressed() {
+        let mut sets = DisjointSet::new(100_000);
+        for id in 1..sets.len() {
+            sets.union(id - 1, id);
+        }
+        assert_eq!(1, sets.component_count());
+        assert_eq!(100_000, sets.component_size(99_999));
+    }
+
+    #[rstest]
+    #[case(&[], 0)]
+    #[case(&[("a", "b")], 1)]
+    #[case(&[("a", "b"), ("c", "d")], 2)]
+    #[case(&[("a", "b"), ("c", "d"), ("d", "a")], 1)]
+    #[case(&[("a", "a")], 1)]
+    fn keyed_component_count(#[case] pairs: &[(&str, &str)], #[case] expected: usize) {
+        let mut sets = KeyedDisjointSet::new();
+        for (a, b) in pairs {
+            sets.union(*a, *b);
+        }
+        assert_eq!(expected, sets.component_count());
+    }
+
+    #[test]
+    fn keyed() {
+        let mut sets = KeyedDisjointSet::new();
+        sets.union((0, 0), (0, 1));
+        sets.union((5, 5), (5, 6));
+        sets.insert((9, 9));
+        sets.union((0, 1), (1, 1));
+        assert_eq!(6, sets.len());
+        assert!(sets.connected(&(0, 0), &(1, 1)));
+        assert!(!sets.connected(&(0, 0), &(5, 5)));
+        assert!(!sets.connected(&(0, 0), &(7, 7)));
+        assert_eq!(Some(3), sets.component_size(&(1, 1)));
+        assert_eq!(None, sets.component_size(&(7, 7)));
+        assert_eq!(sets.find(&(0, 0)).cloned(), sets.find(&(1, 1)).cloned());
+        assert_eq!(
+            vec![
+                vec![(0, 0), (0, 1), (1, 1)],
+                vec![(5, 5), (5, 6)],
+                vec![(9, 9)]
+            ],
+            sets.components()
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use super::number_theory::Gcd;
+use super::point::Point;
+
+/// Where a point lies relative to a polygon.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Consecutive vertices, closing the polygon from the last vertex back to the first.
+fn edges(polygon: &[Point<i64>]) -> impl Iterator<Item = (Point<i64>, Point<i64>)> + '_ {
+    polygon
+        .iter()
+        .copied()
+        .zip(polygon.iter().copied().cycle().skip(1))
+}
+
+/// Twice the area enclosed by the vertices using the shoelace formula, which keeps it an
+/// integer. Positive for vertices running clockwise with `y` growing downwards, negative for
+/// counter-clockwise ones.
+pub fn signed_double_area(polygon: &[Point<i64>]) -> i64 {
+    edges(polygon).map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+}
+
+/// Twice the area regardless of the orientation of the vertices.
+pub fn double_area(polygon: &[Point<i64>]) -> i64 {
+    signed_double_area(polygon).abs()
+}
+
+/// Number of lattice points on the edges of the polygon, vertices included.
+pub fn boundary_points(polygon: &[Point<i64>]) -> i64 {
+    edges(polygon)
+        .map(|(a, b)| {
+            let difference = b - a;
+            difference.x.gcd(difference.y)
+        })
+        .sum()
+}
+
+/// Number of lattice points strictly inside a simple polygon on lattice vertices, following
+/// Pick's theorem `A = I + B / 2 - 1`. Degenerate polygons without area have none.
+pub fn interior_points(polygon: &[Point<i64>]) -> i64 {
+    match double_area(polygon) {
+        0 => 0,
+        area => (area - boundary_points(polygon) + 2) / 2,
+    }
+}
+
+/// Number of lattice points inside or on the edges of the polygon, e.g. the cells of a dug out
+/// lagoon whose outline runs through the cell centres.
+pub fn enclosed_points(polygon: &[Point<i64>]) -> i64 {
+    interior_points(polygon) + boundary_points(polygon)
+}
+
+fn is_on_segment(a: Point<i64>, b: Point<i64>, point: Point<i64>) -> bool {
+    let (edge, offset) = (b - a, point - a);
+    edge.x * offset.y == edge.y * offset.x
+        && a.x.min(b.x) <= point.x
+        && point.x <= a.x.max(b.x)
+        && a.y.min(b.y) <= point.y
+        && point.y <= a.y.max(b.y)
+}
+
+/// Locates `point` relative to a simple polygon by counting the edges crossed by a ray
+/// towards positive `x`. Exact, since all intersections are compared in integers.
+pub fn locate(polygon: &[Point<i64>], point: Point<i64>) -> Location {
+    let mut inside = false;
+    for (a, b) in edges(polygon) {
+        if is_on_segment(a, b, point) {
+            return Location::Boundary;
+        }
+        if (a.y > point.y) != (b.y > point.y) {
+            // the edge crosses the ray's line right of the point if
+            // point.x < a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y)
+            let left = (point.x - a.x) * (b.y - a.y);
+            let right = (point.y - a.y) * (b.x - a.x);
+            if (b.y > a.y && left < right) || (b.y < a.y && left > right) {
+                inside = !inside;
+            }
+        }
+    }
+    match inside {
+        true => Location::Inside,
+        false => Location::Outside,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{
+        boundary_points, double_area, enclosed_points, interior_points, locate, signed_double_area,
+        Location, Point,
+    };
+
+    fn polygon(vertices: &[(i64, i64)]) -> Vec<Point<i64>> {
+        vertices.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    fn reversed(mut polygon: Vec<Point<i64>>) -> Vec<Point<i64>> {
+        polygon.reverse();
+        polygon
+    }
+
+    /// Outline of the lagoon dug in the example of 2023 day 18.
+    fn lagoon() -> Vec<Point<i64>> {
+        polygon(&[
+            (0, 0),
+            (6, 0),
+            (6, 5),
+            (4, 5),
+            (4, 7),
+            (6, 7),
+            (6, 9),
+            (1, 9),
+            (1, 7),
+            (0, 7),
+            (0, 5),
+            (2, 5),
+            (2, 2),
+            (0, 2),
+        ])
+    }
+
+    #[rstest]
+    #[case(polygon(&[(0, 0), (4, 0), (4, 3), (0, 3)]), 24, 14, 6)]
+    #[case(polygon(&[(0, 0), (4, 0), (0, 3)]), 12, 8, 3)]
+    #[case(polygon(&[(0, 0), (2, 1), (1, 3), (-1, 2)]), 10, 4, 4)]
+    #[case(lagoon(), 84, 38, 24)]
+    #[case(polygon(&[(0, 0), (5, 5)]), 0, 10, 0)]
+    #[case(polygon(&[]), 0, 0, 0)]
+    fn lattice_counts(
+        #[case] polygon: Vec<Point<i64>>,
+        #[case] expected_double_area: i64,
+        #[case] expected_boundary: i64,
+        #[case] expected_interior: i64,
+    ) {
+        for polygon in [polygon.clone(), reversed(polygon)] {
+            assert_eq!(expected_double_area, double_area(&polygon));
+            assert_eq!(expected_boundary, boundary_points(&polygon));
+            assert_eq!(expected_interior, interior_points(&polygon));
+        }
+    }
+
+    #[test]
+    fn orientation() {
+        let clockwise = polygon(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+        assert_eq!(24, signed_double_area(&clockwise));
+        assert_eq!(-24, signed_double_area(&reversed(clockwise)));
+    }
+
+    #[test]
+    fn lagoon_volume() {
+        assert_eq!(62, enclosed_points(&lagoon()));
+        assert_eq!(62, enclosed_points(&reversed(lagoon())));
+    }
+
+    #[rstest]
+    #[case((3, 1), Location::Inside)]
+    #[case((3, 4), Location::Inside)]
+    #[case((1, 6), Location::Inside)]
+    #[case((2, 8), Location::Inside)]
+    #[case((1, 8), Location::Boundary)]
+    #[case((4, 6), Location::Boundary)]
+    #[case((6, 9), Location::Boundary)]
+    #[case((0, 6), Location::Boundary)]
+    #[case((1, 2), Location::Boundary)]
+    #[case((1, 3), Location::Outside)]
+    #[case((0, 4), Location::Outside)]
+    #[case((5, 6), Location::Outside)]
+    #[case((0, 8), Location::Outside)]
+    #[case((-1, 0), Location::Outside)]
+    #[case((7, 2), Location::Outside)]
+    fn locate_in_lagoon(#[case] (x, y): (i64, i64), #[case] expected: Location) {
+        assert_eq!(expected, locate(&lagoon(), Point::new(x, y)));
+        assert_eq!(expected, locate(&reversed(lagoon()), Point::new(x, y)));
+    }
+
+    #[test]
+    fn locate_agrees_with_pick() {
+        let outline = lagoon();
+        let inside = (-1..=7)
+            .flat_map(|x| (-1..=10).map(move |y| Point::new(x, y)))
+            .filter(|&p| locate(&outline, p) == Location::Inside)
+            .count();
+        assert_eq!(interior_points(&outline), inside as i64);
+    }
+}
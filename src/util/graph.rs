@@ -0,0 +1,334 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use std::fmt::Debug;
+
+#[derive(Debug, PartialEq)]
+pub enum Error<N> {
+    /// The graph contains a cycle, given as nodes where every one has an edge to the next and
+    /// the last one an edge back to the first.
+    Cycle(Vec<N>),
+}
+
+impl<N: Debug> std::fmt::Display for Error<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Cycle(nodes) => write!(f, "graph contains the cycle {:?}", nodes),
+        }
+    }
+}
+
+/// Directed graph over ordered nodes. Nodes are kept sorted, which makes every traversal and
+/// thereby every result deterministic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiGraph<N> {
+    edges: BTreeMap<N, BTreeSet<N>>,
+}
+
+impl<N: Clone + Ord> Default for DiGraph<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Clone + Ord> DiGraph<N> {
+    pub fn new() -> Self {
+        Self {
+            edges: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.edges.entry(node).or_default();
+    }
+
+    /// Adds an edge and both of its nodes. Returns `false` if the edge already existed.
+    pub fn add_edge(&mut self, from: N, to: N) -> bool {
+        self.add_node(to.clone());
+        self.edges.entry(from).or_default().insert(to)
+    }
+
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.edges.contains_key(node)
+    }
+
+    pub fn contains_edge(&self, from: &N, to: &N) -> bool {
+        self.edges.get(from).is_some_and(|s| s.contains(to))
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.values().map(|s| s.len()).sum()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.edges.keys()
+    }
+
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.edges.get(node).into_iter().flatten()
+    }
+
+    /// Keeps only the nodes matching `keep` and the edges between them.
+    pub fn subgraph(&self, keep: impl Fn(&N) -> bool) -> Self {
+        Self {
+            edges: self
+                .edges
+                .iter()
+                .filter(|(node, _)| keep(node))
+                .map(|(node, successors)| {
+                    let successors = successors.iter().filter(|n| keep(n)).cloned().collect();
+                    (node.clone(), successors)
+                })
+                .collect(),
+        }
+    }
+
+    /// Maps nodes to dense indices in their sorted order, returning the adjacency lists.
+    fn indexed(&self) -> (Vec<&N>, Vec<Vec<usize>>) {
+        let nodes = self.edges.keys().collect::<Vec<&N>>();
+        let adjacency = self
+            .edges
+            .values()
+            .map(|successors| {
+                successors
+                    .iter()
+                    .map(|n| nodes.binary_search(&n).unwrap())
+                    .collect()
+            })
+            .collect();
+        (nodes, adjacency)
+    }
+
+    /// Orders the nodes so every edge points forward, using Kahn's algorithm. Whenever several
+    /// nodes are available the smallest one comes first.
+    pub fn topological_sort(&self) -> Result<Vec<N>, Error<N>> {
+        let (nodes, adjacency) = self.indexed();
+        let mut in_degree = vec![0usize; nodes.len()];
+        for successor in adjacency.iter().flatten() {
+            in_degree[*successor] += 1;
+        }
+        let mut available = (0..nodes.len())
+            .filter(|i| in_degree[*i] == 0)
+            .map(Reverse)
+            .collect::<BinaryHeap<_>>();
+        let mut sorted = Vec::with_capacity(nodes.len());
+        while let Some(Reverse(index)) = available.pop() {
+            sorted.push(nodes[index].clone());
+            for successor in &adjacency[index] {
+                in_degree[*successor] -= 1;
+                if in_degree[*successor] == 0 {
+                    available.push(Reverse(*successor));
+                }
+            }
+        }
+        if sorted.len() == nodes.len() {
+            Ok(sorted)
+        } else {
+            Err(Error::Cycle(
+                Self::cycle_among(&adjacency, &in_degree)
+                    .into_iter()
+                    .map(|i| nodes[i].clone())
+                    .collect(),
+            ))
+        }
+    }
+
+    /// Every node left over by Kahn's algorithm has a predecessor that is left over as well,
+    /// so walking backwards through those must eventually repeat a node.
+    fn cycle_among(adjacency: &[Vec<usize>], in_degree: &[usize]) -> Vec<usize> {
+        let mut predecessor = vec![None; adjacency.len()];
+        for (from, successors) in adjacency.iter().enumerate() {
+            for to in successors {
+                if in_degree[from] > 0 && predecessor[*to].is_none() {
+                    predecessor[*to] = Some(from);
+                }
+            }
+        }
+        let mut position_in_walk = vec![None; adjacency.len()];
+        let mut walk = Vec::new();
+        let mut current = in_degree.iter().position(|d| *d > 0).unwrap();
+        while position_in_walk[current].is_none() {
+            position_in_walk[current] = Some(walk.len());
+            walk.push(current);
+            current = predecessor[current].unwrap();
+        }
+        let mut cycle = walk.split_off(position_in_walk[current].unwrap());
+        cycle.reverse();
+        // start at the smallest node so the same cycle is always reported the same way
+        let smallest = (0..cycle.len()).min_by_key(|i| cycle[*i]).unwrap();
+        cycle.rotate_left(smallest);
+        cycle
+    }
+
+    pub fn find_cycle(&self) -> Option<Vec<N>> {
+        match self.topological_sort() {
+            Ok(_) => None,
+            Err(Error::Cycle(cycle)) => Some(cycle),
+        }
+    }
+
+    /// Strongly connected components found with Tarjan's algorithm. The components are listed
+    /// in topological order of the condensed graph, the nodes within each one sorted.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        let (nodes, adjacency) = self.indexed();
+        let mut index = vec![None::<usize>; nodes.len()];
+        let mut low_link = vec![0usize; nodes.len()];
+        let mut on_stack = vec![false; nodes.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0usize;
+        for root in 0..nodes.len() {
+            if index[root].is_some() {
+                continue;
+            }
+            // explicit call stack of (node, next successor to look at) instead of recursion
+            let mut calls = vec![(root, 0usize)];
+            while let Some((node, child)) = calls.pop() {
+                if child == 0 {
+                    index[node] = Some(next_index);
+                    low_link[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                } else {
+                    let previous = adjacency[node][child - 1];
+                    low_link[node] = low_link[node].min(low_link[previous]);
+                }
+                match adjacency[node][child..]
+                    .iter()
+                    .position(|s| index[*s].is_none())
+                {
+                    Some(offset) => {
+                        for successor in &adjacency[node][child..child + offset] {
+                            if on_stack[*successor] {
+                                low_link[node] = low_link[node].min(index[*successor].unwrap());
+                            }
+                        }
+                        let successor = adjacency[node][child + offset];
+                        calls.push((node, child + offset + 1));
+                        calls.push((successor, 0));
+                    }
+                    None => {
+                        for successor in &adjacency[node][child..] {
+                            if on_stack[*successor] {
+                                low_link[node] = low_link[node].min(index[*successor].unwrap());
+                            }
+                        }
+                        if Some(low_link[node]) == index[node] {
+                            let mut component = Vec::new();
+                            while let Some(member) = stack.pop() {
+                                on_stack[member] = false;
+                                component.push(nodes[member].clone());
+                                if member == node {
+                                    break;
+                                }
+                            }
+                            component.sort();
+                            components.push(component);
+                        }
+                    }
+                }
+            }
+        }
+        components.reverse();
+        components
+    }
+}
+
+impl<N: Clone + Ord> FromIterator<(N, N)> for DiGraph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(iter: I) -> Self {
+        let mut graph = Self::new();
+        for (from, to) in iter {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{DiGraph, Error};
+
+    #[test]
+    fn construction() {
+        let mut graph = DiGraph::new();
+        assert!(graph.add_edge('a', 'b'));
+        assert!(!graph.add_edge('a', 'b'));
+        graph.add_edge('b', 'c');
+        graph.add_node('z');
+        assert_eq!(4, graph.node_count());
+        assert_eq!(2, graph.edge_count());
+        assert!(graph.contains_edge(&'a', &'b'));
+        assert!(!graph.contains_edge(&'b', &'a'));
+        assert_eq!(vec![&'c'], graph.successors(&'b').collect::<Vec<_>>());
+        assert_eq!(0, graph.successors(&'q').count());
+        let subgraph = graph.subgraph(|n| *n != 'b');
+        assert_eq!(3, subgraph.node_count());
+        assert_eq!(0, subgraph.edge_count());
+    }
+
+    #[rstest]
+    #[case(vec![(5, 11), (7, 11), (7, 8), (3, 8), (3, 10), (11, 2), (11, 9), (11, 10), (8, 9)], Ok(vec![3, 5, 7, 8, 11, 2, 9, 10]))]
+    #[case(vec![(1, 2), (2, 3), (3, 1), (3, 4)], Err(Error::Cycle(vec![1, 2, 3])))]
+    #[case(vec![(0, 1), (1, 2), (2, 3), (3, 1)], Err(Error::Cycle(vec![1, 2, 3])))]
+    #[case(vec![(4, 4)], Err(Error::Cycle(vec![4])))]
+    #[case(vec![], Ok(vec![]))]
+    fn topological_sort(
+        #[case] edges: Vec<(u32, u32)>,
+        #[case] expected: Result<Vec<u32>, Error<u32>>,
+    ) {
+        let graph = edges.into_iter().collect::<DiGraph<u32>>();
+        assert_eq!(expected, graph.topological_sort());
+    }
+
+    #[test]
+    fn cycle_is_closed() {
+        let graph = [(1, 2), (2, 5), (5, 3), (3, 4), (4, 2), (0, 1)]
+            .into_iter()
+            .collect::<DiGraph<u8>>();
+        let cycle = graph.find_cycle().unwrap();
+        assert_eq!(4, cycle.len());
+        for (i, node) in cycle.iter().enumerate() {
+            assert!(graph.contains_edge(node, &cycle[(i + 1) % cycle.len()]));
+        }
+        assert_eq!(None, graph.subgraph(|n| *n != 4).find_cycle());
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+        let graph = [
+            ('a', 'b'),
+            ('b', 'c'),
+            ('c', 'a'),
+            ('b', 'd'),
+            ('d', 'e'),
+            ('e', 'f'),
+            ('f', 'd'),
+            ('g', 'f'),
+            ('g', 'h'),
+            ('h', 'g'),
+        ]
+        .into_iter()
+        .collect::<DiGraph<char>>();
+        assert_eq!(
+            vec![vec!['g', 'h'], vec!['a', 'b', 'c'], vec!['d', 'e', 'f']],
+            graph.strongly_connected_components()
+        );
+    }
+
+    #[test]
+    fn components_of_dag_are_single_nodes() {
+        let graph = [(1, 2), (1, 3), (3, 2)]
+            .into_iter()
+            .collect::<DiGraph<i32>>();
+        assert_eq!(
+            vec![vec![1], vec![3], vec![2]],
+            graph.strongly_connected_components()
+        );
+    }
+}
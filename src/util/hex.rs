@@ -0,0 +1,432 @@
+use std::collections::HashMap;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    InvalidDirection(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidDirection(text) => write!(f, "'{}' is not a hex direction", text),
+        }
+    }
+}
+
+/// Cell of a hexagonal grid in axial coordinates. The third cube coordinate is implied by
+/// `q + r + s = 0`, see [`Hex::s`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    /// Offsets of the six adjacent cells.
+    const NEIGHBOUR_OFFSETS: [Hex; 6] = [
+        Hex { q: 0, r: -1 },
+        Hex { q: 1, r: -1 },
+        Hex { q: 1, r: 0 },
+        Hex { q: 0, r: 1 },
+        Hex { q: -1, r: 1 },
+        Hex { q: -1, r: 0 },
+    ];
+
+    pub fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// Converts from cube coordinates, `None` unless they sum up to zero.
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Option<Self> {
+        (q + r + s == 0).then_some(Self { q, r })
+    }
+
+    pub fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    pub fn step(&self, direction: impl HexDirection) -> Self {
+        *self + direction.to_hex()
+    }
+
+    /// Number of steps between both cells.
+    pub fn distance(&self, other: &Self) -> i64 {
+        let difference = *self - *other;
+        (difference.q.abs() + difference.r.abs() + difference.s().abs()) / 2
+    }
+
+    /// The six adjacent cells.
+    pub fn neighbours(&self) -> impl Iterator<Item = Self> {
+        let hex = *self;
+        Self::NEIGHBOUR_OFFSETS.into_iter().map(move |o| hex + o)
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            q: self.q + rhs.q,
+            r: self.r + rhs.r,
+        }
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            q: self.q - rhs.q,
+            r: self.r - rhs.r,
+        }
+    }
+}
+
+impl Neg for Hex {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            q: -self.q,
+            r: -self.r,
+        }
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Self;
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self {
+            q: self.q * rhs,
+            r: self.r * rhs,
+        }
+    }
+}
+
+/// One of the six directions between adjacent cells of a hex grid.
+pub trait HexDirection: Copy {
+    /// Offset of the adjacent cell in this direction.
+    fn to_hex(self) -> Hex;
+}
+
+macro_rules! hex_direction_impl {
+    ($direction:ident, [$($variant:ident => $text:literal, ($q:literal, $r:literal)),+]) => {
+        impl $direction {
+            /// All six directions in clockwise order.
+            pub const ALL: [$direction; 6] = [$($direction::$variant),+];
+
+            fn from_index(index: usize) -> Self {
+                Self::ALL[index % 6]
+            }
+
+            fn index(self) -> usize {
+                self as usize
+            }
+
+            /// Rotates by 60 degrees clockwise.
+            pub fn turn_right(self) -> Self {
+                Self::from_index(self.index() + 1)
+            }
+
+            /// Rotates by 60 degrees counterclockwise.
+            pub fn turn_left(self) -> Self {
+                Self::from_index(self.index() + 5)
+            }
+
+            pub fn opposite(self) -> Self {
+                Self::from_index(self.index() + 3)
+            }
+
+            /// Splits a path of directions written without separators, like `nwwswee`.
+            pub fn parse_path(path: &str) -> Result<Vec<Self>, Error> {
+                let mut directions = Vec::new();
+                let mut rest = path;
+                while !rest.is_empty() {
+                    let (direction, text) = Self::ALL
+                        .iter()
+                        .map(|d| (*d, d.to_string()))
+                        .filter(|(_, text)| rest.starts_with(text.as_str()))
+                        .max_by_key(|(_, text)| text.len())
+                        .ok_or_else(|| Error::InvalidDirection(rest.to_string()))?;
+                    directions.push(direction);
+                    rest = &rest[text.len()..];
+                }
+                Ok(directions)
+            }
+        }
+
+        impl HexDirection for $direction {
+            fn to_hex(self) -> Hex {
+                match self {
+                    $($direction::$variant => Hex { q: $q, r: $r }),+
+                }
+            }
+        }
+
+        /// Parses the lowercase compass abbreviation, e.g. `nw`.
+        impl std::str::FromStr for $direction {
+            type Err = Error;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($text => Ok($direction::$variant),)+
+                    _ => Err(Error::InvalidDirection(s.to_string())),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $direction {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $($direction::$variant => write!(f, $text)),+
+                }
+            }
+        }
+    };
+}
+
+/// Direction on a grid of hexagons with flat tops, where cells are stacked in columns.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FlatDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+hex_direction_impl!(
+    FlatDirection,
+    [
+        North => "n", (0, -1),
+        NorthEast => "ne", (1, -1),
+        SouthEast => "se", (1, 0),
+        South => "s", (0, 1),
+        SouthWest => "sw", (-1, 1),
+        NorthWest => "nw", (-1, 0)
+    ]
+);
+
+/// Direction on a grid of hexagons with pointy tops, where cells are lined up in rows.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PointyDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+hex_direction_impl!(
+    PointyDirection,
+    [
+        East => "e", (1, 0),
+        SouthEast => "se", (0, 1),
+        SouthWest => "sw", (-1, 1),
+        West => "w", (-1, 0),
+        NorthWest => "nw", (0, -1),
+        NorthEast => "ne", (1, -1)
+    ]
+);
+
+/// Hex grid without fixed dimensions, only occupied cells are stored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HexMap<T> {
+    cells: HashMap<Hex, T>,
+}
+
+impl<T> Default for HexMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> HexMap<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, hex: Hex) -> Option<&T> {
+        self.cells.get(&hex)
+    }
+
+    pub fn get_mut(&mut self, hex: Hex) -> Option<&mut T> {
+        self.cells.get_mut(&hex)
+    }
+
+    pub fn contains(&self, hex: Hex) -> bool {
+        self.cells.contains_key(&hex)
+    }
+
+    pub fn insert(&mut self, hex: Hex, value: T) -> Option<T> {
+        self.cells.insert(hex, value)
+    }
+
+    pub fn remove(&mut self, hex: Hex) -> Option<T> {
+        self.cells.remove(&hex)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Hex, &T)> {
+        self.cells.iter().map(|(hex, value)| (*hex, value))
+    }
+
+    /// Iterates over the occupied cells among the six adjacent ones.
+    pub fn neighbours(&self, hex: Hex) -> impl Iterator<Item = (Hex, &T)> + '_ {
+        hex.neighbours()
+            .filter_map(move |n| self.cells.get(&n).map(|value| (n, value)))
+    }
+}
+
+impl<T> FromIterator<(Hex, T)> for HexMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Hex, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Error, FlatDirection, Hex, HexDirection, HexMap, PointyDirection};
+
+    fn walk<D: HexDirection>(directions: &[D]) -> Hex {
+        directions.iter().fold(Hex::ORIGIN, |hex, d| hex.step(*d))
+    }
+
+    #[rstest]
+    #[case("ne,ne,ne", 3)]
+    #[case("ne,ne,sw,sw", 0)]
+    #[case("ne,ne,s,s", 2)]
+    #[case("se,sw,se,sw,sw", 3)]
+    fn flat_distance(#[case] path: &str, #[case] expected: i64) {
+        let directions = path
+            .split(',')
+            .map(|d| d.parse::<FlatDirection>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(expected, walk(&directions).distance(&Hex::ORIGIN));
+    }
+
+    #[rstest]
+    #[case("esew", Ok(Hex::new(0, 1)))]
+    #[case("nwwswee", Ok(Hex::ORIGIN))]
+    #[case("", Ok(Hex::ORIGIN))]
+    #[case("ene", Ok(Hex::new(2, -1)))]
+    #[case("ens", Err(Error::InvalidDirection("ns".to_string())))]
+    fn pointy_path(#[case] path: &str, #[case] expected: Result<Hex, Error>) {
+        assert_eq!(
+            expected,
+            PointyDirection::parse_path(path).map(|directions| walk(&directions))
+        );
+    }
+
+    #[rstest]
+    #[case("nw", Ok(FlatDirection::NorthWest))]
+    #[case("s", Ok(FlatDirection::South))]
+    #[case("e", Err(Error::InvalidDirection("e".to_string())))]
+    fn parse_flat(#[case] text: &str, #[case] expected: Result<FlatDirection, Error>) {
+        assert_eq!(expected, text.parse());
+    }
+
+    #[test]
+    fn rotation() {
+        assert_eq!(FlatDirection::NorthEast, FlatDirection::North.turn_right());
+        assert_eq!(FlatDirection::NorthWest, FlatDirection::North.turn_left());
+        assert_eq!(PointyDirection::West, PointyDirection::East.opposite());
+        for direction in PointyDirection::ALL {
+            assert_eq!(-direction.to_hex(), direction.opposite().to_hex());
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+        }
+        for direction in FlatDirection::ALL {
+            assert_eq!(-direction.to_hex(), direction.opposite().to_hex());
+            assert_eq!(direction, direction.turn_right().turn_left());
+        }
+    }
+
+    #[test]
+    fn neighbours() {
+        let hex = Hex::new(2, -3);
+        assert_eq!(6, hex.neighbours().count());
+        assert!(hex.neighbours().all(|n| n.distance(&hex) == 1));
+        let mut expected = PointyDirection::ALL.map(|d| hex.step(d));
+        let mut actual = hex.neighbours().collect::<Vec<_>>();
+        expected.sort();
+        actual.sort();
+        assert_eq!(expected.to_vec(), actual);
+    }
+
+    #[rstest]
+    #[case(Hex::new(1, -3), Hex::new(-2, 2), 5)]
+    #[case(Hex::new(0, 0), Hex::new(3, 0), 3)]
+    #[case(Hex::new(5, 5), Hex::new(5, 5), 0)]
+    fn distance(#[case] a: Hex, #[case] b: Hex, #[case] expected: i64) {
+        assert_eq!(expected, a.distance(&b));
+        assert_eq!(expected, b.distance(&a));
+    }
+
+    #[test]
+    fn cube_coordinates() {
+        assert_eq!(Some(Hex::new(1, -3)), Hex::from_cube(1, -3, 2));
+        assert_eq!(None, Hex::from_cube(1, -3, 1));
+        assert_eq!(2, Hex::new(1, -3).s());
+        assert_eq!(Hex::new(3, -9), Hex::new(1, -3) * 3);
+    }
+
+    #[test]
+    fn flip_tiles() {
+        // example of 2020 day 24, flipping the tile at the end of every path
+        let input = "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+        let mut black: HexMap<()> = HexMap::new();
+        for line in input.lines() {
+            let tile = walk(&PointyDirection::parse_path(line).unwrap());
+            if black.remove(tile).is_none() {
+                black.insert(tile, ());
+            }
+        }
+        assert_eq!(10, black.len());
+        let (tile, _) = black.iter().next().unwrap();
+        assert!(black.neighbours(tile).all(|(n, _)| n.distance(&tile) == 1));
+        assert!(black.contains(tile));
+    }
+}
@@ -0,0 +1,235 @@
+use std::ops::{Add, Range, Sub};
+
+/// Set of values stored as sorted, non-overlapping and non-adjacent half-open ranges, so
+/// touching ranges like `1..3` and `3..5` are merged into `1..5`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Range<T>>,
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of disjoint ranges the set consists of.
+    pub fn interval_count(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Total length covered by the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::default(), |sum, r| sum + (r.end - r.start))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.intervals.iter().cloned()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.intervals.partition_point(|r| r.end <= *value);
+        self.intervals.get(index).is_some_and(|r| r.start <= *value)
+    }
+
+    /// Whether every value of `range` is in the set. Empty ranges are always contained.
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let index = self.intervals.partition_point(|r| r.end <= range.start);
+        self.intervals
+            .get(index)
+            .is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    /// Whether any value of `range` is in the set.
+    pub fn overlaps(&self, range: &Range<T>) -> bool {
+        let index = self.intervals.partition_point(|r| r.end <= range.start);
+        !range.is_empty()
+            && self
+                .intervals
+                .get(index)
+                .is_some_and(|r| r.start < range.end)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // every interval touching the new one is merged into it
+        let first = self.intervals.partition_point(|r| r.end < range.start);
+        let last = self.intervals.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|r| r.end <= range.start);
+        let last = self.intervals.partition_point(|r| r.start < range.end);
+        if first >= last {
+            return;
+        }
+        let left = self.intervals[first].start..range.start;
+        let right = range.end..self.intervals[last - 1].end;
+        let remainders = [left, right].into_iter().filter(|r| !r.is_empty());
+        self.intervals.splice(first..last, remainders);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.insert(range);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0usize, 0usize);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (&self.intervals[i], &other.intervals[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+
+    /// Start of the first run of at least `length` values within `bounds` that are not in
+    /// the set.
+    pub fn first_gap(&self, bounds: Range<T>, length: T) -> Option<T> {
+        let mut cursor = bounds.start;
+        let first = self.intervals.partition_point(|r| r.end <= bounds.start);
+        for range in &self.intervals[first..] {
+            if range.start >= bounds.end {
+                break;
+            }
+            if range.start > cursor && range.start - cursor >= length {
+                return Some(cursor);
+            }
+            cursor = cursor.max(range.end);
+        }
+        (bounds.end > cursor && bounds.end - cursor >= length).then_some(cursor)
+    }
+}
+
+impl<T> FromIterator<Range<T>> for IntervalSet<T>
+where
+    T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use std::ops::Range;
+
+    use super::IntervalSet;
+
+    fn set(ranges: &[Range<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[rstest]
+    #[case(&[1..3, 5..7], vec![1..3, 5..7])]
+    #[case(&[1..3, 3..7], vec![1..7])]
+    #[case(&[5..7, 1..3, 2..6], vec![1..7])]
+    #[case(&[1..10, 3..4], vec![1..10])]
+    #[case(&[1..2, 4..5, 7..8, 3..8], vec![1..2, 3..8])]
+    #[case(&[4..4, -3..-1], vec![-3..-1])]
+    fn insert(#[case] ranges: &[Range<i32>], #[case] expected: Vec<Range<i32>>) {
+        assert_eq!(expected, set(ranges).iter().collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case(2..4, vec![0..2, 4..10, 20..30])]
+    #[case(0..10, vec![20..30])]
+    #[case(5..25, vec![0..5, 25..30])]
+    #[case(10..20, vec![0..10, 20..30])]
+    #[case(-5..100, vec![])]
+    fn remove(#[case] range: Range<i32>, #[case] expected: Vec<Range<i32>>) {
+        let mut set = set(&[0..10, 20..30]);
+        set.remove(range);
+        assert_eq!(expected, set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn membership() {
+        let set = set(&[0..10, 20..30]);
+        assert!(set.contains(&0));
+        assert!(set.contains(&29));
+        assert!(!set.contains(&10));
+        assert!(!set.contains(&-1));
+        assert!(set.contains_range(&(2..8)));
+        assert!(!set.contains_range(&(8..22)));
+        assert!(set.contains_range(&(15..15)));
+        assert!(set.overlaps(&(8..22)));
+        assert!(!set.overlaps(&(10..20)));
+        assert_eq!(20, set.len());
+        assert_eq!(2, set.interval_count());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 40..50]);
+        assert_eq!(set(&[0..30, 40..50]), a.union(&b));
+        assert_eq!(set(&[5..10, 20..25]), a.intersection(&b));
+        assert_eq!(set(&[0..5, 25..30]), a.difference(&b));
+        assert_eq!(set(&[10..20, 40..50]), b.difference(&a));
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+
+    #[rstest]
+    #[case(0..100, 1, Some(3))]
+    #[case(0..100, 2, Some(5))]
+    #[case(0..100, 3, Some(12))]
+    #[case(0..14, 3, None)]
+    #[case(6..100, 1, Some(6))]
+    #[case(0..3, 1, None)]
+    fn first_gap(#[case] bounds: Range<u64>, #[case] length: u64, #[case] expected: Option<u64>) {
+        let set = [0..3, 4..5, 7..12]
+            .into_iter()
+            .collect::<IntervalSet<u64>>();
+        assert_eq!(expected, set.first_gap(bounds, length));
+    }
+}
@@ -0,0 +1,194 @@
+use super::bitset::BitGrid;
+use super::position::Position;
+use super::size::Size;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The list cannot hold more than 256 distinct byte values.
+    ListTooLong(usize),
+    /// A length exceeds the size of the list, so there is no sublist to reverse.
+    InvalidLength(usize),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::ListTooLong(size) => write!(f, "a list of {} elements exceeds 256", size),
+            Error::InvalidLength(length) => write!(f, "length {} exceeds the list", length),
+        }
+    }
+}
+
+/// Appended to the lengths of every dense hash.
+const LENGTH_SUFFIX: [usize; 5] = [17, 31, 73, 47, 23];
+
+const LIST_SIZE: usize = 256;
+
+const ROUNDS: usize = 64;
+
+/// The list `0..size` after `rounds` rounds of reversing sublists of the given lengths, keeping
+/// the position and skip size from one round to the next.
+pub fn sparse_hash(size: usize, lengths: &[usize], rounds: usize) -> Result<Vec<u8>, Error> {
+    if size > LIST_SIZE {
+        return Err(Error::ListTooLong(size));
+    }
+    if let Some(length) = lengths.iter().find(|l| **l > size) {
+        return Err(Error::InvalidLength(*length));
+    }
+    let mut list = (0..size).map(|i| i as u8).collect::<Vec<_>>();
+    // the list is kept rotated so the current position is at its start, avoiding wrap-around
+    let mut offset = 0;
+    let mut skip = 0;
+    for _ in 0..rounds {
+        for length in lengths {
+            list[..*length].reverse();
+            let step = (length + skip) % size.max(1);
+            list.rotate_left(step);
+            offset = (offset + step) % size.max(1);
+            skip += 1;
+        }
+    }
+    list.rotate_right(offset);
+    Ok(list)
+}
+
+/// Knot hash of the bytes of `input`.
+pub fn knot_hash(input: &str) -> DenseHash {
+    let lengths = input
+        .bytes()
+        .map(usize::from)
+        .chain(LENGTH_SUFFIX)
+        .collect::<Vec<_>>();
+    let sparse = sparse_hash(LIST_SIZE, &lengths, ROUNDS).expect("byte lengths fit the list");
+    let mut dense = [0u8; 16];
+    for (byte, block) in dense.iter_mut().zip(sparse.chunks_exact(16)) {
+        *byte = block.iter().fold(0, |acc, b| acc ^ b);
+    }
+    DenseHash(dense)
+}
+
+/// 128 bit result of [`knot_hash`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DenseHash(pub [u8; 16]);
+
+impl DenseHash {
+    pub fn to_hex(self) -> String {
+        self.to_string()
+    }
+
+    /// All 128 bits, most significant first.
+    pub fn bits(&self) -> impl Iterator<Item = bool> + '_ {
+        self.0
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |bit| byte >> bit & 1 == 1))
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.0.iter().map(|byte| byte.count_ones()).sum()
+    }
+}
+
+impl std::fmt::Display for DenseHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+/// 128 by 128 grid with one row per hash of `key-row`, where set bits mark used squares.
+pub fn hash_grid(key: &str) -> BitGrid {
+    let mut grid = BitGrid::new(Size {
+        width: 128,
+        height: 128,
+    });
+    for y in 0..128 {
+        let hash = knot_hash(&format!("{}-{}", key, y));
+        for (x, used) in hash.bits().enumerate() {
+            if used {
+                grid.insert(&Position { x, y });
+            }
+        }
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{hash_grid, knot_hash, sparse_hash, DenseHash, Error, Position};
+    use crate::util::disjoint_set::DisjointSet;
+
+    #[test]
+    fn single_round() {
+        let list = sparse_hash(5, &[3, 4, 1, 5], 1).unwrap();
+        assert_eq!(vec![3, 4, 2, 1, 0], list);
+        assert_eq!(12, list[0] as u32 * list[1] as u32);
+    }
+
+    #[rstest]
+    #[case(5, &[6], Err(Error::InvalidLength(6)))]
+    #[case(257, &[1], Err(Error::ListTooLong(257)))]
+    #[case(5, &[], Ok(vec![0, 1, 2, 3, 4]))]
+    #[case(5, &[5, 0, 5], Ok(vec![3, 4, 0, 1, 2]))]
+    fn sparse(
+        #[case] size: usize,
+        #[case] lengths: &[usize],
+        #[case] expected: Result<Vec<u8>, Error>,
+    ) {
+        assert_eq!(expected, sparse_hash(size, lengths, 1));
+    }
+
+    #[rstest]
+    #[case("", "a2582a3a0e66e6e86e3812dcb672a272")]
+    #[case("AoC 2017", "33efeb34ea91902bb2f59c9920caa6cd")]
+    #[case("1,2,3", "3efbe78a8d82f29979031a4aa0b16a9d")]
+    #[case("1,2,4", "63960835bcdc130f0b66d7ff4f6a5a8e")]
+    fn dense_hash(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(expected, knot_hash(input).to_hex());
+    }
+
+    #[test]
+    fn hash_bits() {
+        let hash = DenseHash([0xa0, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x80]);
+        let set = hash
+            .bits()
+            .enumerate()
+            .filter(|(_, bit)| *bit)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0, 2, 15, 120], set);
+        assert_eq!(4, hash.count_ones());
+    }
+
+    #[test]
+    fn disk_defragmentation() {
+        // example of 2017 day 14
+        let grid = hash_grid("flqrgnkx");
+        let corner = "\
+##.#.#..
+.#.#.#.#
+....#.#.
+#.#.##.#
+.##.#...
+##..#..#
+.#...#..
+##.#.##.";
+        for (y, line) in corner.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                assert_eq!(c == '#', grid.contains(&Position { x, y }), "{} {}", x, y);
+            }
+        }
+        assert_eq!(8108, grid.count());
+
+        let mut regions = DisjointSet::new(128 * 128);
+        for p in grid.iter() {
+            for neighbour in [Position { x: p.x + 1, ..p }, Position { y: p.y + 1, ..p }] {
+                if grid.contains(&neighbour) {
+                    regions.union(p.y * 128 + p.x, neighbour.y * 128 + neighbour.x);
+                }
+            }
+        }
+        let free = 128 * 128 - grid.count();
+        assert_eq!(1242, regions.component_count() - free);
+    }
+}
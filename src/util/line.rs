@@ -0,0 +1,193 @@
+use super::number_theory::Gcd;
+use super::point::{Point, Vector};
+use super::position::Position;
+use super::size::Size;
+
+macro_rules! reduced_impl {
+    ($signed:ty) => {
+        impl Vector<$signed> {
+            /// Divides both components by their greatest common divisor, so stepping by the
+            /// result visits every lattice point along the vector. A zero vector stays zero.
+            pub fn reduced(self) -> Self {
+                match (self.x as i64).gcd(self.y as i64) {
+                    0 => self,
+                    divisor => Self {
+                        x: self.x / divisor as $signed,
+                        y: self.y / divisor as $signed,
+                    },
+                }
+            }
+        }
+    };
+}
+
+reduced_impl!(i32);
+reduced_impl!(i64);
+reduced_impl!(isize);
+
+/// Iterates over every lattice point on the segment from `start` to `end`, both inclusive.
+pub fn lattice_points(start: Point<i64>, end: Point<i64>) -> impl Iterator<Item = Point<i64>> {
+    let difference = end - start;
+    let step = difference.reduced();
+    let count = difference.x.gcd(difference.y);
+    (0..=count).map(move |i| start + step * i)
+}
+
+/// Rasterises the segment from `start` to `end` with Bresenham's algorithm, yielding both
+/// endpoints and exactly one point per step along the major axis.
+pub struct BresenhamLine {
+    current: Option<Point<i64>>,
+    end: Point<i64>,
+    dx: i64,
+    dy: i64,
+    step_x: i64,
+    step_y: i64,
+    error: i64,
+}
+
+impl BresenhamLine {
+    pub fn new(start: Point<i64>, end: Point<i64>) -> Self {
+        let dx = (end.x - start.x).abs();
+        let dy = -(end.y - start.y).abs();
+        Self {
+            current: Some(start),
+            end,
+            dx,
+            dy,
+            step_x: (end.x - start.x).signum(),
+            step_y: (end.y - start.y).signum(),
+            error: dx + dy,
+        }
+    }
+}
+
+impl Iterator for BresenhamLine {
+    type Item = Point<i64>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.current?;
+        if point == self.end {
+            self.current = None;
+        } else {
+            let mut next = point;
+            let doubled_error = 2 * self.error;
+            if doubled_error >= self.dy {
+                self.error += self.dy;
+                next.x += self.step_x;
+            }
+            if doubled_error <= self.dx {
+                self.error += self.dx;
+                next.y += self.step_y;
+            }
+            self.current = Some(next);
+        }
+        Some(point)
+    }
+}
+
+/// Walks from a start position in fixed steps until leaving the area described by a [`Size`].
+///
+/// The start itself is yielded first if it lies within the area. A zero step yields only the
+/// start.
+pub struct Ray {
+    current: Option<Position>,
+    step: Vector<isize>,
+    size: Size,
+}
+
+impl Ray {
+    pub fn new(start: Position, step: Vector<isize>, size: Size) -> Self {
+        Self {
+            current: Some(start).filter(|p| p.is_within_size(&size)),
+            step,
+            size,
+        }
+    }
+}
+
+impl Iterator for Ray {
+    type Item = Position;
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.current?;
+        self.current = if self.step == Vector::default() {
+            None
+        } else {
+            position
+                .checked_add(self.step)
+                .filter(|p| p.is_within_size(&self.size))
+        };
+        Some(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{lattice_points, BresenhamLine, Point, Position, Ray, Size, Vector};
+
+    #[rstest]
+    #[case(Vector::new(6, -4), Vector::new(3, -2))]
+    #[case(Vector::new(0, -7), Vector::new(0, -1))]
+    #[case(Vector::new(5, 3), Vector::new(5, 3))]
+    #[case(Vector::new(0, 0), Vector::new(0, 0))]
+    fn reduced(#[case] vector: Vector<i64>, #[case] expected: Vector<i64>) {
+        assert_eq!(expected, vector.reduced());
+        assert_eq!(
+            Vector::new(expected.x as isize, expected.y as isize),
+            Vector::new(vector.x as isize, vector.y as isize).reduced()
+        );
+    }
+
+    #[test]
+    fn lattice_points_on_segment() {
+        assert_eq!(
+            vec![Point::new(1, 1), Point::new(4, -1), Point::new(7, -3)],
+            lattice_points(Point::new(1, 1), Point::new(7, -3)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Point::new(2, 2)],
+            lattice_points(Point::new(2, 2), Point::new(2, 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), Point::new(3, 0), vec![(0, 0), (1, 0), (2, 0), (3, 0)])]
+    #[case(Point::new(0, 0), Point::new(-2, -2), vec![(0, 0), (-1, -1), (-2, -2)])]
+    #[case(Point::new(0, 0), Point::new(4, 2), vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)])]
+    #[case(Point::new(1, 5), Point::new(2, 1), vec![(1, 5), (1, 4), (2, 3), (2, 2), (2, 1)])]
+    #[case(Point::new(3, 3), Point::new(3, 3), vec![(3, 3)])]
+    fn bresenham(
+        #[case] start: Point<i64>,
+        #[case] end: Point<i64>,
+        #[case] expected: Vec<(i64, i64)>,
+    ) {
+        assert_eq!(
+            expected,
+            BresenhamLine::new(start, end)
+                .map(|p| (p.x, p.y))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    #[case(Position { x: 1, y: 1 }, Vector::new(2, 1), vec![(1, 1), (3, 2), (5, 3)])]
+    #[case(Position { x: 4, y: 3 }, Vector::new(-3, -2), vec![(4, 3), (1, 1)])]
+    #[case(Position { x: 0, y: 0 }, Vector::new(0, 0), vec![(0, 0)])]
+    #[case(Position { x: 9, y: 0 }, Vector::new(1, 1), vec![])]
+    fn ray(
+        #[case] start: Position,
+        #[case] step: Vector<isize>,
+        #[case] expected: Vec<(usize, usize)>,
+    ) {
+        let size = Size {
+            width: 6,
+            height: 4,
+        };
+        assert_eq!(
+            expected,
+            Ray::new(start, step, size)
+                .map(|p| (p.x, p.y))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use std::ops::{Add, Mul, Sub};
+
+use super::{Error, Matrix};
+use crate::util::num::Numeric;
+
+impl<T: Numeric> Matrix<T> {
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = Self::init(T::ZERO, size, size);
+        for i in 0..size {
+            matrix[(i, i)] = T::ONE;
+        }
+        matrix
+    }
+
+    pub fn scaled(&self, scalar: T) -> Self {
+        self.map(|value| *value * scalar)
+    }
+
+    /// Computes the matrix product `self * rhs`.
+    pub fn product(&self, rhs: &Self) -> Result<Self, Error> {
+        if self.columns != rhs.rows {
+            return Err(Error::IncompatibleMatrixSize);
+        }
+        let mut result = Self::init(T::ZERO, self.rows, rhs.columns);
+        for row in 0..self.rows {
+            for k in 0..self.columns {
+                let factor = self[(row, k)];
+                for column in 0..rhs.columns {
+                    result[(row, column)] = result[(row, column)] + factor * rhs[(k, column)];
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Raises a square matrix to the given power using exponentiation by squaring.
+    pub fn pow(&self, exponent: u64) -> Result<Self, Error> {
+        if self.rows != self.columns {
+            return Err(Error::IncompatibleMatrixSize);
+        }
+        let mut result = Self::identity(self.rows);
+        let mut base = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.product(&base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.product(&base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    fn zip_with(&self, rhs: &Self, operation: impl Fn(T, T) -> T) -> Result<Self, Error> {
+        if self.size() != rhs.size() {
+            return Err(Error::IncompatibleMatrixSize);
+        }
+        Ok(Self {
+            rows: self.rows,
+            columns: self.columns,
+            data: self
+                .data
+                .iter()
+                .zip(rhs.data.iter())
+                .map(|(a, b)| operation(*a, *b))
+                .collect(),
+        })
+    }
+}
+
+/// Panics if the matrices differ in size.
+impl<T: Numeric> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+            .expect("tried to add matrices of different sizes")
+    }
+}
+
+/// Panics if the matrices differ in size.
+impl<T: Numeric> Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+            .expect("tried to subtract matrices of different sizes")
+    }
+}
+
+/// Panics if the column count of the left matrix differs from the row count of the right one.
+impl<T: Numeric> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.product(rhs)
+            .expect("tried to multiply matrices of incompatible sizes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::super::{Error, Matrix};
+
+    #[test]
+    fn identity() {
+        assert_eq!(
+            Matrix::from_vec(vec![1, 0, 0, 0, 1, 0, 0, 0, 1], 3, 3).unwrap(),
+            Matrix::<i32>::identity(3)
+        );
+    }
+
+    #[test]
+    fn add_sub() {
+        let a = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+        let b = Matrix::from_vec(vec![6, 5, 4, 3, 2, 1], 2, 3).unwrap();
+        assert_eq!(Matrix::init(7, 2, 3), &a + &b);
+        assert_eq!(
+            Matrix::from_vec(vec![-5, -3, -1, 1, 3, 5], 2, 3).unwrap(),
+            &a - &b
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "different sizes")]
+    fn add_mismatched() {
+        let _ = &Matrix::<u8>::new(2, 3) + &Matrix::<u8>::new(3, 2);
+    }
+
+    #[rstest]
+    #[case(Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap(), Matrix::from_vec(vec![7, 8, 9, 10, 11, 12], 3, 2).unwrap(), Ok(Matrix::from_vec(vec![58, 64, 139, 154], 2, 2).unwrap()))]
+    #[case(Matrix::from_vec(vec![1, 2, 3], 1, 3).unwrap(), Matrix::from_vec(vec![4, 5, 6], 3, 1).unwrap(), Ok(Matrix::from_vec(vec![32], 1, 1).unwrap()))]
+    #[case(Matrix::from_vec(vec![4, 5, 6], 3, 1).unwrap(), Matrix::from_vec(vec![1, 2, 3], 1, 3).unwrap(), Ok(Matrix::from_vec(vec![4, 8, 12, 5, 10, 15, 6, 12, 18], 3, 3).unwrap()))]
+    #[case(Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap(), Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap(), Err(Error::IncompatibleMatrixSize))]
+    fn product(
+        #[case] a: Matrix<i64>,
+        #[case] b: Matrix<i64>,
+        #[case] expected: Result<Matrix<i64>, Error>,
+    ) {
+        assert_eq!(expected, a.product(&b));
+    }
+
+    #[test]
+    fn scaled() {
+        let m = Matrix::from_vec(vec![1.5, -2.0], 1, 2).unwrap();
+        assert_eq!(
+            Matrix::from_vec(vec![3.0, -4.0], 1, 2).unwrap(),
+            m.scaled(2.0)
+        );
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 1)]
+    #[case(10, 55)]
+    #[case(90, 2880067194370816120)]
+    fn fibonacci_pow(#[case] n: u64, #[case] expected: u64) {
+        let m = Matrix::<u64>::from_vec(vec![1, 1, 1, 0], 2, 2).unwrap();
+        assert_eq!(expected, m.pow(n).unwrap()[(0, 1)]);
+    }
+
+    #[test]
+    fn pow_non_square() {
+        assert_eq!(
+            Err(Error::IncompatibleMatrixSize),
+            Matrix::<u8>::new(2, 3).pow(2)
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use super::{Error, Matrix};
+use crate::util::bigint::BigInt;
+use crate::util::rational::Rational;
+
+impl<T: Copy + Into<Rational>> Matrix<T> {
+    pub fn to_rational(&self) -> Matrix<Rational> {
+        self.map(|value| (*value).into())
+    }
+}
+
+impl Matrix<Rational> {
+    /// Scales every row by the product of its denominators. Returns the integer matrix together
+    /// with the product of all scale factors.
+    fn to_integers(&self) -> (Matrix<BigInt>, BigInt) {
+        let mut data = Vec::with_capacity(self.rows * self.columns);
+        let mut total_scale = BigInt::from(1);
+        for row in self.rows() {
+            let scale = row.iter().fold(BigInt::from(1), |acc, x| {
+                acc * BigInt::from(x.denominator())
+            });
+            data.extend(
+                row.iter().map(|x| {
+                    BigInt::from(x.numerator()) * (&scale / BigInt::from(x.denominator()))
+                }),
+            );
+            total_scale = total_scale * scale;
+        }
+        let integers = Matrix::from_vec(data, self.rows, self.columns).unwrap();
+        (integers, total_scale)
+    }
+
+    pub fn determinant(&self) -> Result<Rational, Error> {
+        if self.rows != self.columns {
+            return Err(Error::IncompatibleMatrixSize);
+        }
+        let (mut integers, scale) = self.to_integers();
+        let (pivots, swapped) = integers.eliminate();
+        if pivots.len() < self.rows {
+            return Ok(Rational::ZERO);
+        }
+        let determinant = match self.rows {
+            0 => BigInt::from(1),
+            n => integers[(n - 1, n - 1)].clone(),
+        };
+        let determinant = if swapped { -determinant } else { determinant };
+        fraction(&determinant, &scale)
+    }
+
+    /// Solves `self * x = rhs` for a square coefficient matrix. The elimination runs on big
+    /// integers, so only the solution itself has to fit into `i128` fractions.
+    ///
+    /// Fails with [`Error::SingularMatrix`] if the system has no unique solution and with
+    /// [`Error::Overflow`] if a component of the solution does not fit.
+    pub fn solve(&self, rhs: &[Rational]) -> Result<Vec<Rational>, Error> {
+        if self.rows != self.columns || rhs.len() != self.rows {
+            return Err(Error::IncompatibleMatrixSize);
+        }
+        let mut augmented = self.clone();
+        augmented.insert_column(self.columns, rhs.to_vec())?;
+        let (mut integers, _) = augmented.to_integers();
+        let (pivots, _) = integers.eliminate();
+        if pivots.len() < self.rows || pivots.contains(&self.columns) {
+            return Err(Error::SingularMatrix);
+        }
+        // all pivots of the reduced form are equal, so x_i = rhs_i / pivot as in Cramer's rule
+        (0..self.rows)
+            .map(|row| fraction(&integers[(row, self.columns)], &integers[(row, row)]))
+            .collect()
+    }
+}
+
+impl Matrix<BigInt> {
+    /// Fraction-free Gauss-Jordan elimination after Bareiss. Every division by the previous
+    /// pivot is exact, so the entries stay integers no larger than minors of the matrix.
+    /// Returns the pivot columns and whether an odd number of row swaps happened. With full
+    /// rank every pivot ends up as the determinant.
+    fn eliminate(&mut self) -> (Vec<usize>, bool) {
+        let mut pivots = Vec::new();
+        let mut swapped = false;
+        let mut previous_pivot = BigInt::from(1);
+        let mut row = 0usize;
+        for column in 0..self.columns {
+            if row == self.rows {
+                break;
+            }
+            let Some(pivot_row) = (row..self.rows).find(|r| !self[(*r, column)].is_zero()) else {
+                continue;
+            };
+            if pivot_row != row {
+                for c in 0..self.columns {
+                    self.data
+                        .swap(row * self.columns + c, pivot_row * self.columns + c);
+                }
+                swapped = !swapped;
+            }
+            let pivot = self[(row, column)].clone();
+            for r in (0..self.rows).filter(|r| *r != row) {
+                let factor = self[(r, column)].clone();
+                for c in 0..self.columns {
+                    let eliminated = &pivot * &self[(r, c)] - &factor * &self[(row, c)];
+                    self[(r, c)] = eliminated / &previous_pivot;
+                }
+            }
+            previous_pivot = pivot;
+            pivots.push(column);
+            row += 1;
+        }
+        (pivots, swapped)
+    }
+}
+
+/// The fraction in lowest terms, [`Error::Overflow`] if it does not fit into a [`Rational`].
+fn fraction(numerator: &BigInt, denominator: &BigInt) -> Result<Rational, Error> {
+    let (mut a, mut b) = (numerator.abs(), denominator.abs());
+    while !b.is_zero() {
+        (a, b) = (b.clone(), &a % &b);
+    }
+    let (numerator, denominator) = (numerator / &a, denominator / &a);
+    numerator
+        .to_i128()
+        .zip(denominator.to_i128())
+        .and_then(|(numerator, denominator)| Rational::new(numerator, denominator))
+        .ok_or(Error::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::super::{Error, Matrix};
+    use super::Rational;
+
+    fn r(numerator: i128, denominator: i128) -> Rational {
+        Rational::new(numerator, denominator).unwrap()
+    }
+
+    #[rstest]
+    #[case(Matrix::from_vec(vec![2, 1, -1, -3, -1, 2, -2, 1, 2], 3, 3).unwrap(), vec![8, -11, -3], Ok(vec![r(2, 1), r(3, 1), r(-1, 1)]))]
+    #[case(Matrix::from_vec(vec![0, 1, 1, 0], 2, 2).unwrap(), vec![3, 4], Ok(vec![r(4, 1), r(3, 1)]))]
+    #[case(Matrix::from_vec(vec![2, 1, 1, 3], 2, 2).unwrap(), vec![1, 1], Ok(vec![r(2, 5), r(1, 5)]))]
+    #[case(Matrix::from_vec(vec![1, 2, 2, 4], 2, 2).unwrap(), vec![1, 2], Err(Error::SingularMatrix))]
+    #[case(Matrix::from_vec(vec![1, 2, 2, 4], 2, 2).unwrap(), vec![1, 3], Err(Error::SingularMatrix))]
+    #[case(Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap(), vec![1, 2], Err(Error::IncompatibleMatrixSize))]
+    fn solve(
+        #[case] coefficients: Matrix<i128>,
+        #[case] rhs: Vec<i128>,
+        #[case] expected: Result<Vec<Rational>, Error>,
+    ) {
+        let rhs = rhs.into_iter().map(Rational::from).collect::<Vec<_>>();
+        assert_eq!(expected, coefficients.to_rational().solve(&rhs));
+    }
+
+    #[test]
+    fn claw_machine() {
+        // 94a + 22b = 8400, 34a + 67b = 5400
+        let coefficients = Matrix::<i64>::from_vec(vec![94, 22, 34, 67], 2, 2).unwrap();
+        let solution = coefficients
+            .to_rational()
+            .solve(&[Rational::from(8400), Rational::from(5400)])
+            .unwrap();
+        assert_eq!(
+            vec![Some(80), Some(40)],
+            solution.iter().map(|x| x.to_integer()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let coefficients =
+            Matrix::from_vec(vec![3, i128::MAX / 2, i128::MAX / 2, 1], 2, 2).unwrap();
+        let rhs = [Rational::ONE, Rational::ONE];
+        assert_eq!(Err(Error::Overflow), coefficients.to_rational().solve(&rhs));
+        assert_eq!(
+            Err(Error::Overflow),
+            coefficients.to_rational().determinant()
+        );
+    }
+
+    #[rstest]
+    #[case(
+        vec![
+            0, 207, 11, 0, 152038984768095, 135311050520131,
+            -207, 0, 396, -152038984768095, 0, 252033069114201,
+            -11, -396, 0, -135311050520131, -252033069114201, 0,
+            0, -94, -302, 0, -16295108574138, -50539115283546,
+            94, 0, 61, 16295108574138, 0, 1100038867265,
+            302, -61, 0, 50539115283546, -1100038867265, 0,
+        ],
+        vec![
+            87532307420199200, 137155701185432481, -129897640002674662,
+            -149279561567745788, 42753638196033059, 41326608852614395,
+        ],
+        Ok(vec![243802475379781, 301836584091801, 398388224310688, -271, 176, -45]),
+    )]
+    #[case(
+        vec![
+            0, 116, -218, 0, 149318799221516, -78885175435332,
+            -116, 0, -74, -149318799221516, 0, 70088170483834,
+            218, 74, 0, 78885175435332, -70088170483834, 0,
+            0, -405, -112, 0, -138314819591322, -23438738075841,
+            405, 0, -81, 138314819591322, 0, 77727629153382,
+            112, 81, 0, 23438738075841, -77727629153382, 0,
+        ],
+        vec![
+            -7246739268781436, -40081676024568246, 59677400935406018,
+            -136786612426098672, 41193645402672411, 45231943663079150,
+        ],
+        Ok(vec![151557030307487, 303255396838365, 195188653654308, 15, -43, -83]),
+    )]
+    fn hailstone_sized_systems(
+        #[case] coefficients: Vec<i64>,
+        #[case] rhs: Vec<i64>,
+        #[case] expected: Result<Vec<i128>, Error>,
+    ) {
+        // rock position and velocity from three hailstones, as in 2023 day 24
+        let coefficients = Matrix::from_vec(coefficients, 6, 6).unwrap();
+        let rhs = rhs.into_iter().map(Rational::from).collect::<Vec<_>>();
+        let expected = expected.map(|x| x.into_iter().map(Rational::from).collect::<Vec<_>>());
+        assert_eq!(expected, coefficients.to_rational().solve(&rhs));
+    }
+
+    #[rstest]
+    #[case(Matrix::from_vec(vec![1, 2, 3, 4], 2, 2).unwrap(), Ok(r(-2, 1)))]
+    #[case(Matrix::from_vec(vec![0, 1, 1, 0], 2, 2).unwrap(), Ok(r(-1, 1)))]
+    #[case(Matrix::from_vec(vec![2, 0, 1, 1, 3, 2, 1, 1, 2], 3, 3).unwrap(), Ok(r(6, 1)))]
+    #[case(Matrix::from_vec(vec![1, 2, 2, 4], 2, 2).unwrap(), Ok(r(0, 1)))]
+    #[case(Matrix::from_vec(vec![1, 2], 1, 2).unwrap(), Err(Error::IncompatibleMatrixSize))]
+    fn determinant(#[case] m: Matrix<i32>, #[case] expected: Result<Rational, Error>) {
+        assert_eq!(expected, m.to_rational().determinant());
+    }
+}
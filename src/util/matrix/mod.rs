@@ -0,0 +1,1194 @@
+mod arithmetic;
+mod linear_system;
+mod text;
+
+use std::ops::{Index, IndexMut, Range};
+
+use super::size::Size;
+use iterators::{RowIterator, RowIteratorMut};
+pub use views::{MatrixView, Windows};
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    IncompatibleMatrixSize,
+    IndexOutOfBounds,
+    SingularMatrix,
+    /// Exact arithmetic on the entries does not fit into `i128` fractions.
+    Overflow,
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// A character that does not fit into a single byte.
+    NonAscii {
+        row: usize,
+        column: usize,
+        character: char,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::IncompatibleMatrixSize => write!(f, "matrix sizes are incompatible"),
+            Error::IndexOutOfBounds => write!(f, "index is out of the matrix bounds"),
+            Error::SingularMatrix => write!(f, "matrix is singular"),
+            Error::Overflow => write!(f, "matrix arithmetic overflowed"),
+            Error::RaggedRow {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "row {} has {} cells but {} were expected",
+                row, actual, expected
+            ),
+            Error::NonAscii {
+                row,
+                column,
+                character,
+            } => write!(
+                f,
+                "'{}' at row {}, column {} is not ASCII",
+                character, row, column
+            ),
+        }
+    }
+}
+
+/// Row and column offsets of the four orthogonally adjacent cells.
+pub const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Row and column offsets of all eight surrounding cells.
+pub const SURROUNDING_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix<T> {
+    rows: usize,
+    columns: usize,
+    data: Box<[T]>,
+}
+
+impl<T: Copy> Matrix<T> {
+    pub fn init(initial_value: T, rows: usize, columns: usize) -> Self {
+        let size = rows * columns;
+        let data = vec![initial_value; size].into_boxed_slice();
+        Self {
+            rows,
+            columns,
+            data,
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn from_vec(vector: Vec<T>, rows: usize, columns: usize) -> Result<Self, Error> {
+        if vector.len() != rows * columns {
+            Err(Error::IncompatibleMatrixSize)
+        } else {
+            Ok(Self {
+                rows,
+                columns,
+                data: vector.into_boxed_slice(),
+            })
+        }
+    }
+
+    pub fn rows<'a>(&'a self) -> RowIterator<'a, T> {
+        RowIterator::new(self)
+    }
+
+    pub fn rows_mut<'a>(&'a mut self) -> RowIteratorMut<'a, T> {
+        RowIteratorMut::new(self)
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.columns
+    }
+
+    pub fn size(&self) -> Size {
+        Size {
+            width: self.columns,
+            height: self.rows,
+        }
+    }
+
+    pub fn transposed(&self) -> Matrix<&T> {
+        let original_columns = self.columns;
+        let original_rows = self.rows;
+        let mut borrow_vector: Vec<&T> = Vec::new();
+        if original_columns != 0 && original_rows != 0 {
+            if original_columns == 1 || original_rows == 1 {
+                borrow_vector = self.data.iter().collect();
+            } else {
+                for column in 0..original_columns {
+                    for row in 0..original_rows {
+                        borrow_vector.push(&self[(row, column)]);
+                    }
+                }
+            }
+        }
+        Matrix::<&T> {
+            rows: original_columns,
+            columns: original_rows,
+            data: borrow_vector.into_boxed_slice(),
+        }
+    }
+
+    pub fn transposed_mut(&mut self) -> Matrix<&mut T> {
+        let original_columns = self.columns;
+        let original_rows = self.rows;
+        let borrowed_refs: Vec<&mut T>;
+        if original_columns == 0 || original_rows == 0 {
+            borrowed_refs = Vec::new();
+        } else if original_rows == 1 || original_columns == 1 {
+            borrowed_refs = self.data.iter_mut().collect();
+        } else {
+            let mut borrow_vector: Vec<*mut T> = Vec::new();
+            for column in 0..original_columns {
+                for row in 0..original_rows {
+                    borrow_vector.push(&mut self[(row, column)] as *mut T);
+                }
+            }
+            unsafe {
+                borrowed_refs = borrow_vector.into_iter().map(|ptr| &mut *ptr).collect();
+            }
+        }
+        Matrix::<&mut T> {
+            rows: original_columns,
+            columns: original_rows,
+            data: borrowed_refs.into_boxed_slice(),
+        }
+    }
+
+    pub fn into_transposed(self) -> Self {
+        let original_colums = self.columns;
+        let original_rows = self.rows;
+        let vector: Vec<T>;
+        if original_rows == 0 || original_colums == 0 {
+            vector = Vec::new();
+        } else if original_rows == 1 || original_colums == 1 {
+            vector = self.data.into_vec();
+        } else {
+            let mut temp: Vec<(usize, T)> = self
+                .data
+                .into_vec()
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    let new_col = index / original_colums;
+                    let new_row = index % original_colums;
+                    let new_index = new_row * original_rows + new_col;
+                    (new_index, value)
+                })
+                .collect();
+            temp.sort_by_key(|(index, _)| *index);
+            vector = temp.into_iter().map(|(_, val)| val).collect();
+        }
+        Self {
+            rows: original_colums,
+            columns: original_rows,
+            data: vector.into_boxed_slice(),
+        }
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row < self.rows && column < self.columns {
+            Some(&self[(row, column)])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the up to four orthogonally adjacent cells that lie within the matrix.
+    pub fn neighbours(
+        &self,
+        index: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.offset_cells(index, &ORTHOGONAL_OFFSETS)
+    }
+
+    /// Iterates over the up to eight surrounding cells that lie within the matrix.
+    pub fn neighbours_with_diagonals(
+        &self,
+        index: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.offset_cells(index, &SURROUNDING_OFFSETS)
+    }
+
+    fn offset_cells<'a>(
+        &'a self,
+        index: (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        let (row, column) = index;
+        offsets.iter().filter_map(move |(row_offset, column_offset)| {
+            let row = row.checked_add_signed(*row_offset)?;
+            let column = column.checked_add_signed(*column_offset)?;
+            self.get(row, column).map(|value| ((row, column), value))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Matrix<U> {
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// Borrows the cells in `row_range` x `col_range` as a view without copying them.
+    ///
+    /// Panics if either range reaches beyond the matrix.
+    pub fn submatrix(&self, row_range: Range<usize>, col_range: Range<usize>) -> MatrixView<'_, T> {
+        MatrixView::new(self, 0, 0, self.rows, self.columns).submatrix(row_range, col_range)
+    }
+
+    /// Iterates over all `height` x `width` views in row major order of their top left cell.
+    ///
+    /// Panics if `height` or `width` is zero.
+    pub fn windows(&self, height: usize, width: usize) -> Windows<'_, T> {
+        Windows::new(
+            MatrixView::new(self, 0, 0, self.rows, self.columns),
+            height,
+            width,
+        )
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Inserts `row` before the row at `index`, shifting all following rows down.
+    ///
+    /// An empty matrix adopts the length of the inserted row as its column count.
+    pub fn insert_row(&mut self, index: usize, row: Vec<T>) -> Result<(), Error> {
+        if index > self.rows {
+            return Err(Error::IndexOutOfBounds);
+        }
+        if self.rows == 0 {
+            self.columns = row.len();
+        } else if row.len() != self.columns {
+            return Err(Error::IncompatibleMatrixSize);
+        }
+        let mut data = std::mem::take(&mut self.data).into_vec();
+        let offset = index * self.columns;
+        data.splice(offset..offset, row);
+        self.data = data.into_boxed_slice();
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Inserts `column` before the column at `index`, shifting all following columns right.
+    ///
+    /// An empty matrix adopts the length of the inserted column as its row count.
+    pub fn insert_column(&mut self, index: usize, column: Vec<T>) -> Result<(), Error> {
+        if index > self.columns {
+            return Err(Error::IndexOutOfBounds);
+        }
+        if self.columns == 0 {
+            self.rows = column.len();
+        } else if column.len() != self.rows {
+            return Err(Error::IncompatibleMatrixSize);
+        }
+        let mut old_data = std::mem::take(&mut self.data).into_vec().into_iter();
+        let mut data = Vec::with_capacity(self.rows * (self.columns + 1));
+        for value in column {
+            data.extend(old_data.by_ref().take(index));
+            data.push(value);
+            data.extend(old_data.by_ref().take(self.columns - index));
+        }
+        self.data = data.into_boxed_slice();
+        self.columns += 1;
+        Ok(())
+    }
+
+    pub fn remove_row(&mut self, index: usize) -> Result<Vec<T>, Error> {
+        if index >= self.rows {
+            return Err(Error::IndexOutOfBounds);
+        }
+        let mut data = std::mem::take(&mut self.data).into_vec();
+        let offset = index * self.columns;
+        let row = data.drain(offset..offset + self.columns).collect();
+        self.data = data.into_boxed_slice();
+        self.rows -= 1;
+        Ok(row)
+    }
+
+    pub fn remove_column(&mut self, index: usize) -> Result<Vec<T>, Error> {
+        if index >= self.columns {
+            return Err(Error::IndexOutOfBounds);
+        }
+        let mut column = Vec::with_capacity(self.rows);
+        let mut data = Vec::with_capacity(self.rows * (self.columns - 1));
+        for (i, value) in std::mem::take(&mut self.data)
+            .into_vec()
+            .into_iter()
+            .enumerate()
+        {
+            if i % self.columns == index {
+                column.push(value);
+            } else {
+                data.push(value);
+            }
+        }
+        self.data = data.into_boxed_slice();
+        self.columns -= 1;
+        Ok(column)
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Surrounds the matrix with a border of `n` cells on every side, all set to `value`.
+    pub fn pad(&mut self, value: T, n: usize) {
+        let columns = self.columns + 2 * n;
+        let rows = self.rows + 2 * n;
+        let mut data = Vec::with_capacity(rows * columns);
+        data.resize(n * columns, value.clone());
+        for row in self.rows() {
+            data.resize(data.len() + n, value.clone());
+            data.extend_from_slice(row);
+            data.resize(data.len() + n, value.clone());
+        }
+        data.resize(rows * columns, value);
+        self.rows = rows;
+        self.columns = columns;
+        self.data = data.into_boxed_slice();
+    }
+
+    /// Changes the dimensions while keeping every cell at its `(row, column)` index.
+    ///
+    /// Cells outside the new dimensions are dropped, newly added cells are set to `value`.
+    pub fn resize(&mut self, rows: usize, columns: usize, value: T) {
+        let mut data = Vec::with_capacity(rows * columns);
+        for row in self.rows().take(rows) {
+            let kept = columns.min(row.len());
+            data.extend_from_slice(&row[..kept]);
+            data.resize(data.len() + columns - kept, value.clone());
+        }
+        data.resize(rows * columns, value);
+        self.rows = rows;
+        self.columns = columns;
+        self.data = data.into_boxed_slice();
+    }
+}
+
+impl<T: Default + Copy> Matrix<T> {
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self::init(T::default(), rows, columns)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        let (row, column) = index;
+        if row >= self.rows || column >= self.columns {
+            panic!("tried to index matrix out of bounds");
+        } else {
+            &self.data[row * self.columns + column]
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        let (row, column) = index;
+        if row >= self.rows || column >= self.columns {
+            panic!("tried to index matrix out of bounds");
+        } else {
+            &mut self.data[row * self.columns + column]
+        }
+    }
+}
+
+impl<T> Index<usize> for Matrix<T> {
+    type Output = [T];
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= self.rows {
+            panic!("tried to index matrix row out of bounds")
+        } else {
+            &self.data[index * self.columns..(index + 1) * self.columns]
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Matrix<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= self.rows {
+            panic!("tried to index matrix row out of bounds")
+        } else {
+            &mut self.data[index * self.columns..(index + 1) * self.columns]
+        }
+    }
+}
+
+mod views {
+    use std::ops::{Index, Range};
+
+    use super::Matrix;
+    use crate::util::size::Size;
+
+    #[derive(Debug)]
+    pub struct MatrixView<'a, T> {
+        matrix: &'a Matrix<T>,
+        row_offset: usize,
+        col_offset: usize,
+        rows: usize,
+        columns: usize,
+    }
+
+    // derived implementations would require `T: Clone` even though only a reference is copied
+    impl<T> Clone for MatrixView<'_, T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for MatrixView<'_, T> {}
+
+    impl<'a, T> MatrixView<'a, T> {
+        pub(super) fn new(
+            matrix: &'a Matrix<T>,
+            row_offset: usize,
+            col_offset: usize,
+            rows: usize,
+            columns: usize,
+        ) -> Self {
+            Self {
+                matrix,
+                row_offset,
+                col_offset,
+                rows,
+                columns,
+            }
+        }
+
+        pub fn row_count(&self) -> usize {
+            self.rows
+        }
+
+        pub fn col_count(&self) -> usize {
+            self.columns
+        }
+
+        pub fn size(&self) -> Size {
+            Size {
+                width: self.columns,
+                height: self.rows,
+            }
+        }
+
+        pub fn get(&self, row: usize, column: usize) -> Option<&'a T> {
+            if row < self.rows && column < self.columns {
+                Some(&self.matrix[(self.row_offset + row, self.col_offset + column)])
+            } else {
+                None
+            }
+        }
+
+        pub fn row(&self, index: usize) -> Option<&'a [T]> {
+            if index < self.rows {
+                let row = &self.matrix[self.row_offset + index];
+                Some(&row[self.col_offset..self.col_offset + self.columns])
+            } else {
+                None
+            }
+        }
+
+        pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+            (0..self.rows).filter_map(|index| self.row(index))
+        }
+
+        /// Iterates over all cells in row major order.
+        pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+            self.rows().flatten()
+        }
+
+        /// Narrows this view further, with both ranges relative to the view itself.
+        ///
+        /// Panics if either range reaches beyond the view.
+        pub fn submatrix(&self, row_range: Range<usize>, col_range: Range<usize>) -> Self {
+            if row_range.start > row_range.end
+                || col_range.start > col_range.end
+                || row_range.end > self.rows
+                || col_range.end > self.columns
+            {
+                panic!("tried to create sub-matrix out of bounds");
+            }
+            Self {
+                matrix: self.matrix,
+                row_offset: self.row_offset + row_range.start,
+                col_offset: self.col_offset + col_range.start,
+                rows: row_range.len(),
+                columns: col_range.len(),
+            }
+        }
+
+        pub fn windows(&self, height: usize, width: usize) -> Windows<'a, T> {
+            Windows::new(*self, height, width)
+        }
+    }
+
+    impl<T: Clone> MatrixView<'_, T> {
+        pub fn to_matrix(self) -> Matrix<T> {
+            Matrix {
+                rows: self.rows,
+                columns: self.columns,
+                data: self.iter().cloned().collect(),
+            }
+        }
+    }
+
+    impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+        type Output = T;
+        fn index(&self, index: (usize, usize)) -> &Self::Output {
+            let (row, column) = index;
+            self.get(row, column)
+                .expect("tried to index matrix view out of bounds")
+        }
+    }
+
+    impl<T> Index<usize> for MatrixView<'_, T> {
+        type Output = [T];
+        fn index(&self, index: usize) -> &Self::Output {
+            self.row(index)
+                .expect("tried to index matrix view row out of bounds")
+        }
+    }
+
+    impl<T: PartialEq> PartialEq for MatrixView<'_, T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.rows == other.rows && self.columns == other.columns && self.iter().eq(other.iter())
+        }
+    }
+
+    impl<T: PartialEq> PartialEq<Matrix<T>> for MatrixView<'_, T> {
+        fn eq(&self, other: &Matrix<T>) -> bool {
+            self.rows == other.rows
+                && self.columns == other.columns
+                && self.iter().eq(other.data.iter())
+        }
+    }
+
+    pub struct Windows<'a, T> {
+        view: MatrixView<'a, T>,
+        height: usize,
+        width: usize,
+        row: usize,
+        column: usize,
+    }
+
+    impl<'a, T> Windows<'a, T> {
+        pub(super) fn new(view: MatrixView<'a, T>, height: usize, width: usize) -> Self {
+            if height == 0 || width == 0 {
+                panic!("window size must not be zero");
+            }
+            Self {
+                view,
+                height,
+                width,
+                row: 0usize,
+                column: 0usize,
+            }
+        }
+    }
+
+    impl<'a, T> Iterator for Windows<'a, T> {
+        type Item = MatrixView<'a, T>;
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.height > self.view.rows || self.width > self.view.columns {
+                return None;
+            }
+            if self.column + self.width > self.view.columns {
+                self.column = 0;
+                self.row += 1;
+            }
+            if self.row + self.height > self.view.rows {
+                None
+            } else {
+                let window = self.view.submatrix(
+                    self.row..self.row + self.height,
+                    self.column..self.column + self.width,
+                );
+                self.column += 1;
+                Some(window)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use rstest::rstest;
+
+        use super::Matrix;
+        use super::MatrixView;
+
+        fn sample() -> Matrix<u32> {
+            Matrix::from_vec((0..12).collect(), 3, 4).unwrap()
+        }
+
+        #[rstest]
+        #[case(0..2, 0..2, vec![0, 1, 4, 5])]
+        #[case(1..3, 1..4, vec![5, 6, 7, 9, 10, 11])]
+        #[case(2..3, 0..4, vec![8, 9, 10, 11])]
+        #[case(0..3, 3..4, vec![3, 7, 11])]
+        #[case(1..1, 0..4, vec![])]
+        fn submatrix(
+            #[case] row_range: std::ops::Range<usize>,
+            #[case] col_range: std::ops::Range<usize>,
+            #[case] expected: Vec<u32>,
+        ) {
+            let matrix = sample();
+            let view = matrix.submatrix(row_range.clone(), col_range.clone());
+            assert_eq!(row_range.len(), view.row_count());
+            assert_eq!(col_range.len(), view.col_count());
+            assert_eq!(expected, view.iter().copied().collect::<Vec<u32>>());
+        }
+
+        #[rstest]
+        #[should_panic(expected = "out of bounds")]
+        #[case(0..4, 0..1)]
+        #[should_panic(expected = "out of bounds")]
+        #[case(0..1, 2..5)]
+        fn submatrix_out_of_bounds(
+            #[case] row_range: std::ops::Range<usize>,
+            #[case] col_range: std::ops::Range<usize>,
+        ) {
+            sample().submatrix(row_range, col_range);
+        }
+
+        #[test]
+        fn view_indexing() {
+            let matrix = sample();
+            let view = matrix.submatrix(1..3, 1..3);
+            assert_eq!(5, view[(0, 0)]);
+            assert_eq!(10, view[(1, 1)]);
+            assert_eq!(&[9, 10][..], &view[1]);
+            assert_eq!(None, view.get(2, 0));
+            assert_eq!(None, view.row(2));
+        }
+
+        #[test]
+        fn nested_submatrix() {
+            let matrix = sample();
+            let view = matrix.submatrix(1..3, 1..4).submatrix(1..2, 1..3);
+            assert_eq!(
+                Matrix::from_vec(vec![10, 11], 1, 2).unwrap(),
+                view.to_matrix()
+            );
+            assert_eq!(view, Matrix::from_vec(vec![10, 11], 1, 2).unwrap());
+        }
+
+        #[rstest]
+        #[case(2, 2, 6)]
+        #[case(3, 3, 2)]
+        #[case(1, 4, 3)]
+        #[case(3, 4, 1)]
+        #[case(4, 1, 0)]
+        #[case(1, 5, 0)]
+        fn window_count(#[case] height: usize, #[case] width: usize, #[case] expected: usize) {
+            assert_eq!(expected, sample().windows(height, width).count());
+        }
+
+        #[test]
+        fn window_order() {
+            let matrix = sample();
+            let firsts = matrix
+                .windows(2, 3)
+                .map(|window: MatrixView<u32>| window[(0, 0)])
+                .collect::<Vec<u32>>();
+            assert_eq!(vec![0, 1, 4, 5], firsts);
+            let last = matrix.windows(2, 3).last().unwrap();
+            assert_eq!(
+                Matrix::from_vec(vec![5, 6, 7, 9, 10, 11], 2, 3).unwrap(),
+                last.to_matrix()
+            );
+        }
+
+        #[test]
+        fn windows_of_view() {
+            let matrix = sample();
+            let windows = matrix
+                .submatrix(0..2, 1..4)
+                .windows(2, 2)
+                .collect::<Vec<_>>();
+            assert_eq!(2, windows.len());
+            assert_eq!(
+                windows[1],
+                Matrix::from_vec(vec![2, 3, 6, 7], 2, 2).unwrap()
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "must not be zero")]
+        fn zero_sized_window() {
+            sample().windows(0, 1);
+        }
+    }
+}
+
+mod iterators {
+    use super::Matrix;
+
+    pub struct RowIterator<'a, T> {
+        row: usize,
+        matrix: &'a Matrix<T>,
+    }
+
+    impl<'a, T> RowIterator<'a, T> {
+        pub(super) fn new(arg: &'a Matrix<T>) -> Self {
+            Self {
+                row: 0usize,
+                matrix: arg,
+            }
+        }
+    }
+
+    impl<'a, T> Iterator for RowIterator<'a, T> {
+        type Item = &'a [T];
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.row < self.matrix.rows {
+                let row = &self.matrix[self.row];
+                self.row += 1;
+                Some(row)
+            } else {
+                None
+            }
+        }
+    }
+
+    pub struct RowIteratorMut<'a, T> {
+        row: usize,
+        matrix: &'a mut Matrix<T>,
+    }
+
+    impl<'a, T> RowIteratorMut<'a, T> {
+        pub(super) fn new(arg: &'a mut Matrix<T>) -> Self {
+            Self {
+                row: 0usize,
+                matrix: arg,
+            }
+        }
+    }
+
+    impl<'a, T> Iterator for RowIteratorMut<'a, T> {
+        type Item = &'a mut [T];
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.row < self.matrix.rows {
+                let row = &mut self.matrix[self.row] as *mut [T];
+                self.row += 1;
+                unsafe { Some(&mut *row) }
+            } else {
+                None
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Matrix;
+        use super::RowIterator;
+        use super::RowIteratorMut;
+
+        #[test]
+        fn iter() {
+            let matrix = Matrix::<i32>::from_vec(vec![-1, 0, 1, 0, 2, 1], 2, 3).unwrap();
+            let mut iter = RowIterator {
+                row: 0,
+                matrix: &matrix,
+            };
+            assert_eq!(iter.next(), Some(&[-1i32, 0i32, 1i32][..]));
+            assert_eq!(iter.next(), Some(&[0i32, 2i32, 1i32][..]));
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn iter_mut() {
+            let mut matrix = Matrix::<i32>::init(1i32, 3, 3);
+            let mut iter = RowIteratorMut {
+                row: 0,
+                matrix: &mut matrix,
+            };
+
+            assert_eq!(iter.next(), Some(&mut [1, 1, 1][..]));
+            assert_eq!(iter.next(), Some(&mut [1, 1, 1][..]));
+            assert_eq!(iter.next(), Some(&mut [1, 1, 1][..]));
+            assert_eq!(iter.next(), None);
+
+            let iter = RowIteratorMut {
+                row: 0,
+                matrix: &mut matrix,
+            };
+
+            for (i, row) in iter.enumerate() {
+                for (j, val) in row.iter_mut().enumerate() {
+                    *val = (i as i32) * 10 + j as i32;
+                }
+            }
+
+            assert_eq!(
+                matrix,
+                Matrix::from_vec(vec![0, 1, 2, 10, 11, 12, 20, 21, 22], 3, 3).unwrap()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use std::fmt::Debug;
+    use std::ops::AddAssign;
+    use std::usize;
+
+    use crate::util::size::Size;
+
+    use super::Error;
+    use super::Matrix;
+
+    #[rstest]
+    #[case(5, 2, Matrix::<u8> { rows: 5, columns: 2, data: vec![0u8;10].into_boxed_slice()})]
+    #[case(18, 356, Matrix::<u32> { rows: 18, columns: 356, data: vec![0u32;6408].into_boxed_slice()})]
+    #[case(0, 356, Matrix::<i32> { rows: 0, columns: 356, data: vec![].into_boxed_slice()})]
+    #[case(9, 0, Matrix::<isize> { rows: 9, columns: 0, data: vec![].into_boxed_slice()})]
+    fn new<T: Copy + Default + Debug + PartialEq>(
+        #[case] row_count: usize,
+        #[case] column_count: usize,
+        #[case] expected: Matrix<T>,
+    ) {
+        assert_eq!(expected, Matrix::<T>::new(row_count, column_count));
+    }
+
+    #[rstest]
+    #[case(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3, Ok(Matrix::<i32> { rows: 3, columns: 3, data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9].into_boxed_slice() }))]
+    #[case(vec![1, 2 ], 1, 3, Err(Error::IncompatibleMatrixSize))]
+    #[case(vec![1, 2 ], 1, 1, Err(Error::IncompatibleMatrixSize))]
+    #[case(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 1, 9, Ok(Matrix::<u16> { rows: 1, columns: 9, data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9].into_boxed_slice() }))]
+    #[case(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 9, 1, Ok(Matrix::<u16> { rows: 9, columns: 1, data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9].into_boxed_slice() }))]
+    fn from_vec<T: Debug + PartialEq>(
+        #[case] vector: Vec<T>,
+        #[case] row_count: usize,
+        #[case] column_count: usize,
+        #[case] expected: Result<Matrix<T>, Error>,
+    ) {
+        assert_eq!(
+            expected,
+            Matrix::<T>::from_vec(vector, row_count, column_count)
+        );
+    }
+
+    #[rstest]
+    #[case(12i16, 2, 2, Matrix::<i16> { rows: 2, columns: 2, data: vec![12i16; 4].into_boxed_slice() })]
+    #[case(9i128, 100, 39, Matrix::<i128> { rows: 100, columns: 39, data: vec![9i128; 3900].into_boxed_slice() })]
+    #[case(-1i8, 2, 4000, Matrix::<i8> { rows: 2, columns: 4000, data: vec![-1i8; 8000].into_boxed_slice() })]
+    fn init<T: Copy + Debug + PartialEq>(
+        #[case] initial_value: T,
+        #[case] row_count: usize,
+        #[case] column_count: usize,
+        #[case] expected: Matrix<T>,
+    ) {
+        assert_eq!(
+            expected,
+            Matrix::<T>::init(initial_value, row_count, column_count)
+        );
+    }
+
+    #[rstest]
+    #[case(Matrix::<i16>::new(18, 29), 18, 29)]
+    #[case(Matrix::<i64>::new(99,  4), 99,  4)]
+    #[case(Matrix::<i32>::new( 8,  5),  8,  5)]
+    fn matrix_size<T>(
+        #[case] m: Matrix<T>,
+        #[case] expected_row_count: usize,
+        #[case] expected_column_count: usize,
+    ) {
+        assert_eq!(expected_row_count, m.row_count());
+        assert_eq!(expected_column_count, m.col_count());
+        assert_eq!(
+            Size {
+                width: expected_column_count,
+                height: expected_row_count
+            },
+            m.size()
+        );
+    }
+
+    enum IndexType {
+        Cell((usize, usize)),
+        Row(usize),
+    }
+
+    enum IndexResultType<'a, T> {
+        Cell(&'a T),
+        Row(&'a [T]),
+    }
+
+    #[rstest]
+    #[case(IndexType::Cell((0, 0)), IndexResultType::Cell(&2))]
+    #[case(IndexType::Cell((0, 1)), IndexResultType::Cell(&3))]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((0, 2)), IndexResultType::Cell(&0))]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((0, 9)), IndexResultType::Cell(&0))]
+    #[case(IndexType::Cell((1, 0)), IndexResultType::Cell(&4))]
+    #[case(IndexType::Cell((1, 1)), IndexResultType::Cell(&5))]
+    #[case(IndexType::Cell((2, 0)), IndexResultType::Cell(&1))]
+    #[case(IndexType::Cell((2, 1)), IndexResultType::Cell(&7))]
+    #[case(IndexType::Cell((3, 0)), IndexResultType::Cell(&9))]
+    #[case(IndexType::Cell((3, 1)), IndexResultType::Cell(&0))]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((4, 0)), IndexResultType::Cell(&0))]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((9, 0)), IndexResultType::Cell(&0))]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((4, 9)), IndexResultType::Cell(&0))]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((9, 9)), IndexResultType::Cell(&0))]
+    #[case(IndexType::Row(0),       IndexResultType::Row(&[2, 3]))]
+    #[case(IndexType::Row(1),       IndexResultType::Row(&[4, 5]))]
+    #[case(IndexType::Row(2),       IndexResultType::Row(&[1, 7]))]
+    #[case(IndexType::Row(3),       IndexResultType::Row(&[9, 0]))]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Row(4),       IndexResultType::Row(&[]))]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Row(9),       IndexResultType::Row(&[]))]
+    fn indexing(#[case] index: IndexType, #[case] expected: IndexResultType<usize>) {
+        let matrix = Matrix::<usize>::from_vec(vec![2, 3, 4, 5, 1, 7, 9, 0], 4, 2).unwrap();
+        match (index, expected) {
+            (IndexType::Cell(index), IndexResultType::Cell(expected)) => {
+                assert_eq!(expected, &matrix[index])
+            }
+            (IndexType::Row(index), IndexResultType::Row(expected)) => {
+                assert_eq!(expected, &matrix[index])
+            }
+            _ => panic!("missmatched index types"),
+        }
+    }
+
+    #[rstest]
+    #[case(IndexType::Cell((0, 0)), IndexResultType::Cell(&12), Matrix::<u64> { rows: 2, columns: 2, data: vec![12, 3222, 77, 0].into_boxed_slice()})]
+    #[case(IndexType::Cell((0, 1)), IndexResultType::Cell(&80), Matrix::<u64> { rows: 2, columns: 2, data: vec![90, 80, 77, 0].into_boxed_slice()})]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((0, 2)), IndexResultType::Cell(&0), Matrix::<u64> { rows: 0, columns: 0, data: vec![].into_boxed_slice()})]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((0, 9)), IndexResultType::Cell(&0), Matrix::<u64> { rows: 0, columns: 0, data: vec![].into_boxed_slice()})]
+    #[case(IndexType::Cell((1, 0)), IndexResultType::Cell(&4), Matrix::<u64> { rows: 2, columns: 2, data: vec![90, 3222, 4, 0].into_boxed_slice()})]
+    #[case(IndexType::Cell((1, 1)), IndexResultType::Cell(&13), Matrix::<u64> { rows: 2, columns: 2, data: vec![90, 3222, 77, 13].into_boxed_slice()})]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((2, 0)), IndexResultType::Cell(&0), Matrix::<u64> { rows: 0, columns: 0, data: vec![].into_boxed_slice()})]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((9, 0)), IndexResultType::Cell(&0), Matrix::<u64> { rows: 0, columns: 0, data: vec![].into_boxed_slice()})]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((2, 2)), IndexResultType::Cell(&0), Matrix::<u64> { rows: 0, columns: 0, data: vec![].into_boxed_slice()})]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Cell((9, 9)), IndexResultType::Cell(&0), Matrix::<u64> { rows: 0, columns: 0, data: vec![].into_boxed_slice()})]
+    #[case(IndexType::Row(0), IndexResultType::Row(&[1, 2]), Matrix::<u64> { rows: 2, columns: 2, data: vec![1, 2, 77, 0].into_boxed_slice()})]
+    #[case(IndexType::Row(1), IndexResultType::Row(&[89, 5]), Matrix::<u64> { rows: 2, columns: 2, data: vec![90, 3222, 89, 5].into_boxed_slice()})]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Row(2), IndexResultType::Row(&[]), Matrix::<u64> { rows: 0, columns: 0, data: vec![].into_boxed_slice()})]
+    #[should_panic(expected = "out of bounds")]
+    #[case(IndexType::Row(9), IndexResultType::Row(&[]), Matrix::<u64> { rows: 0, columns: 0, data: vec![].into_boxed_slice()})]
+    fn mut_indexing(
+        #[case] index: IndexType,
+        #[case] value: IndexResultType<u64>,
+        #[case] expected: Matrix<u64>,
+    ) {
+        let mut matrix = Matrix::<u64>::from_vec(vec![90, 3222, 77, 0], 2, 2).unwrap();
+        match (index, value) {
+            (IndexType::Cell(index), IndexResultType::Cell(value)) => matrix[index] = *value,
+            (IndexType::Row(index), IndexResultType::Row(value)) => {
+                for (x, y) in matrix[index].iter_mut().zip(value.iter()) {
+                    *x = *y
+                }
+            }
+            _ => panic!("missmatched index types"),
+        }
+        assert_eq!(expected, matrix);
+    }
+
+    #[rstest]
+    #[case(Matrix::<u128> { rows: 2, columns: 3, data: vec![1, 2, 3, 4, 5, 6].into_boxed_slice() }, Matrix::<&u128> { rows: 3, columns: 2, data: vec![&1, &4, &2, &5, &3, &6].into_boxed_slice() })]
+    #[case(Matrix::<i128> { rows: 3, columns: 2, data: vec![1, 2, 3, 4, 5, 6].into_boxed_slice() }, Matrix::<&i128> { rows: 2, columns: 3, data: vec![&1, &3, &5, &2, &4, &6].into_boxed_slice() })]
+    #[case(Matrix::<u16> { rows: 2, columns: 2, data: vec![1, 2, 3, 4].into_boxed_slice() }, Matrix::<&u16> { rows: 2, columns: 2, data: vec![&1, &3, &2, &4].into_boxed_slice() })]
+    #[case(Matrix::<i16> { rows: 1, columns: 5, data: vec![1, 2, 3, 4, 5].into_boxed_slice() }, Matrix::<&i16> { rows: 5, columns: 1, data: vec![&1, &2, &3, &4, &5].into_boxed_slice() })]
+    #[case(Matrix::<u32> { rows: 5, columns: 1, data: vec![1, 2, 3, 4, 5].into_boxed_slice() }, Matrix::<&u32> { rows: 1, columns: 5, data: vec![&1, &2, &3, &4, &5].into_boxed_slice() })]
+    #[case(Matrix::<u8> { rows: 0, columns: 2, data: vec![].into_boxed_slice() }, Matrix::<&u8> { rows: 2, columns: 0, data: vec![].into_boxed_slice() })]
+    #[case(Matrix::<i8> { rows: 2, columns: 0, data: vec![].into_boxed_slice() }, Matrix::<&i8> { rows: 0, columns: 2, data: vec![].into_boxed_slice() })]
+    #[case(Matrix::<u32> { rows: 0, columns: 0, data: vec![].into_boxed_slice() }, Matrix::<&u32> { rows: 0, columns: 0, data: vec![].into_boxed_slice() })]
+    fn transposed<T: Debug + PartialEq>(#[case] m: Matrix<T>, #[case] expected: Matrix<&T>) {
+        assert_eq!(expected, m.transposed());
+    }
+
+    #[rstest]
+    #[case(Matrix::<i32> { rows: 2, columns: 3, data: vec![1, 2, 3, 4, 5, 6].into_boxed_slice() }, Matrix::<i32> { rows: 2, columns: 3, data: vec![1, 3, 5, 4, 6, 8].into_boxed_slice() })]
+    #[case(Matrix::<u32> { rows: 3, columns: 2, data: vec![1, 2, 3, 4, 5, 6].into_boxed_slice() }, Matrix::<u32> { rows: 3, columns: 2, data: vec![1, 3, 3, 5, 5, 7].into_boxed_slice() })]
+    #[case(Matrix::<u16> { rows: 2, columns: 2, data: vec![1, 2, 3, 4].into_boxed_slice() }, Matrix::<u16> { rows: 2, columns: 2, data: vec![1, 3, 3, 5].into_boxed_slice() })]
+    #[case(Matrix::<i16> { rows: 1, columns: 5, data: vec![1, 2, 3, 4, 5].into_boxed_slice() }, Matrix::<i16> { rows: 1, columns: 5, data: vec![1, 3, 5, 7, 9].into_boxed_slice() })]
+    #[case(Matrix::<i64> { rows: 5, columns: 1, data: vec![1, 2, 3, 4, 5].into_boxed_slice() }, Matrix::<i64> { rows: 5, columns: 1, data: vec![1, 2, 3, 4, 5].into_boxed_slice() })]
+    #[case(Matrix::<u8> { rows: 0, columns: 2, data: vec![].into_boxed_slice() }, Matrix::<u8> { rows: 0, columns: 2, data: vec![].into_boxed_slice() })]
+    #[case(Matrix::<i8> { rows: 2, columns: 0, data: vec![].into_boxed_slice() }, Matrix::<i8> { rows: 2, columns: 0, data: vec![].into_boxed_slice() })]
+    #[case(Matrix::<u32> { rows: 0, columns: 0, data: vec![].into_boxed_slice() }, Matrix::<u32> { rows: 0, columns: 0, data: vec![].into_boxed_slice() })]
+    fn transposed_mut<T: AddAssign + Debug + PartialEq + TryFrom<usize>>(
+        #[case] m: Matrix<T>,
+        #[case] expected: Matrix<T>,
+    ) where
+        <T as TryFrom<usize>>::Error: Debug,
+    {
+        let mut m = m;
+        for (i, row) in m.transposed_mut().rows_mut().enumerate() {
+            for value in row.iter_mut() {
+                **value += T::try_from(i).unwrap()
+            }
+        }
+        assert_eq!(expected, m);
+    }
+
+    #[rstest]
+    #[case(Matrix::<u128> { rows: 2, columns: 3, data: vec![1, 2, 3, 4, 5, 6].into_boxed_slice() }, Matrix::<u128> { rows: 3, columns: 2, data: vec![1, 4, 2, 5, 3, 6].into_boxed_slice() })]
+    #[case(Matrix::<i128> { rows: 3, columns: 2, data: vec![1, 2, 3, 4, 5, 6].into_boxed_slice() }, Matrix::<i128> { rows: 2, columns: 3, data: vec![1, 3, 5, 2, 4, 6].into_boxed_slice() })]
+    #[case(Matrix::<u16> { rows: 2, columns: 2, data: vec![1, 2, 3, 4].into_boxed_slice() }, Matrix::<u16> { rows: 2, columns: 2, data: vec![1, 3, 2, 4].into_boxed_slice() })]
+    #[case(Matrix::<i16> { rows: 1, columns: 5, data: vec![1, 2, 3, 4, 5].into_boxed_slice() }, Matrix::<i16> { rows: 5, columns: 1, data: vec![1, 2, 3, 4, 5].into_boxed_slice() })]
+    #[case(Matrix::<u32> { rows: 5, columns: 1, data: vec![1, 2, 3, 4, 5].into_boxed_slice() }, Matrix::<u32> { rows: 1, columns: 5, data: vec![1, 2, 3, 4, 5].into_boxed_slice() })]
+    #[case(Matrix::<u8> { rows: 0, columns: 2, data: vec![].into_boxed_slice() }, Matrix::<u8> { rows: 2, columns: 0, data: vec![].into_boxed_slice() })]
+    #[case(Matrix::<i8> { rows: 2, columns: 0, data: vec![].into_boxed_slice() }, Matrix::<i8> { rows: 0, columns: 2, data: vec![].into_boxed_slice() })]
+    #[case(Matrix::<u32> { rows: 0, columns: 0, data: vec![].into_boxed_slice() }, Matrix::<u32> { rows: 0, columns: 0, data: vec![].into_boxed_slice() })]
+    fn into_transposed<T: Debug + PartialEq>(#[case] m: Matrix<T>, #[case] expected: Matrix<T>) {
+        assert_eq!(expected, m.into_transposed())
+    }
+
+    #[rstest]
+    #[case((0, 0), vec![((0, 1), 2), ((1, 0), 4)], vec![((0, 1), 2), ((1, 0), 4), ((1, 1), 5)])]
+    #[case((1, 1), vec![((0, 1), 2), ((1, 0), 4), ((1, 2), 6)], vec![((0, 0), 1), ((0, 1), 2), ((0, 2), 3), ((1, 0), 4), ((1, 2), 6)])]
+    #[case((1, 2), vec![((0, 2), 3), ((1, 1), 5)], vec![((0, 1), 2), ((0, 2), 3), ((1, 1), 5)])]
+    #[case((5, 5), vec![], vec![])]
+    fn neighbours(
+        #[case] index: (usize, usize),
+        #[case] expected: Vec<((usize, usize), u8)>,
+        #[case] expected_with_diagonals: Vec<((usize, usize), u8)>,
+    ) {
+        let m = Matrix::<u8>::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+        assert_eq!(
+            expected,
+            m.neighbours(index)
+                .map(|(i, v)| (i, *v))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            expected_with_diagonals,
+            m.neighbours_with_diagonals(index)
+                .map(|(i, v)| (i, *v))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    #[case(0, vec![7, 8, 9], Ok(vec![7, 8, 9, 1, 2, 3, 4, 5, 6]))]
+    #[case(1, vec![7, 8, 9], Ok(vec![1, 2, 3, 7, 8, 9, 4, 5, 6]))]
+    #[case(2, vec![7, 8, 9], Ok(vec![1, 2, 3, 4, 5, 6, 7, 8, 9]))]
+    #[case(3, vec![7, 8, 9], Err(Error::IndexOutOfBounds))]
+    #[case(1, vec![7, 8], Err(Error::IncompatibleMatrixSize))]
+    fn insert_row(
+        #[case] index: usize,
+        #[case] row: Vec<i32>,
+        #[case] expected: Result<Vec<i32>, Error>,
+    ) {
+        let mut m = Matrix::<i32>::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+        match (m.insert_row(index, row), expected) {
+            (Ok(()), Ok(data)) => assert_eq!(Matrix::from_vec(data, 3, 3).unwrap(), m),
+            (actual, expected) => assert_eq!(expected.map(|_| ()), actual),
+        }
+    }
+
+    #[rstest]
+    #[case(0, vec![7, 8], Ok(vec![7, 1, 2, 3, 8, 4, 5, 6]))]
+    #[case(2, vec![7, 8], Ok(vec![1, 2, 7, 3, 4, 5, 8, 6]))]
+    #[case(3, vec![7, 8], Ok(vec![1, 2, 3, 7, 4, 5, 6, 8]))]
+    #[case(4, vec![7, 8], Err(Error::IndexOutOfBounds))]
+    #[case(1, vec![7, 8, 9], Err(Error::IncompatibleMatrixSize))]
+    fn insert_column(
+        #[case] index: usize,
+        #[case] column: Vec<i32>,
+        #[case] expected: Result<Vec<i32>, Error>,
+    ) {
+        let mut m = Matrix::<i32>::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+        match (m.insert_column(index, column), expected) {
+            (Ok(()), Ok(data)) => assert_eq!(Matrix::from_vec(data, 2, 4).unwrap(), m),
+            (actual, expected) => assert_eq!(expected.map(|_| ()), actual),
+        }
+    }
+
+    #[test]
+    fn insert_into_empty() {
+        let mut m = Matrix::<u8>::new(0, 0);
+        m.insert_row(0, vec![1, 2, 3]).unwrap();
+        assert_eq!(Matrix::from_vec(vec![1, 2, 3], 1, 3).unwrap(), m);
+        let mut m = Matrix::<u8>::new(0, 0);
+        m.insert_column(0, vec![1, 2]).unwrap();
+        assert_eq!(Matrix::from_vec(vec![1, 2], 2, 1).unwrap(), m);
+    }
+
+    #[rstest]
+    #[case(0, Ok(vec![1, 2]), vec![3, 4, 5, 6])]
+    #[case(2, Ok(vec![5, 6]), vec![1, 2, 3, 4])]
+    #[case(3, Err(Error::IndexOutOfBounds), vec![1, 2, 3, 4, 5, 6])]
+    fn remove_row(
+        #[case] index: usize,
+        #[case] expected_row: Result<Vec<u16>, Error>,
+        #[case] expected_data: Vec<u16>,
+    ) {
+        let mut m = Matrix::<u16>::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2).unwrap();
+        assert_eq!(expected_row, m.remove_row(index));
+        let rows = expected_data.len() / 2;
+        assert_eq!(Matrix::from_vec(expected_data, rows, 2).unwrap(), m);
+    }
+
+    #[rstest]
+    #[case(0, Ok(vec![1, 3, 5]), vec![2, 4, 6])]
+    #[case(1, Ok(vec![2, 4, 6]), vec![1, 3, 5])]
+    #[case(2, Err(Error::IndexOutOfBounds), vec![1, 2, 3, 4, 5, 6])]
+    fn remove_column(
+        #[case] index: usize,
+        #[case] expected_column: Result<Vec<u16>, Error>,
+        #[case] expected_data: Vec<u16>,
+    ) {
+        let mut m = Matrix::<u16>::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2).unwrap();
+        assert_eq!(expected_column, m.remove_column(index));
+        let columns = expected_data.len() / 3;
+        assert_eq!(Matrix::from_vec(expected_data, 3, columns).unwrap(), m);
+    }
+
+    #[rstest]
+    #[case(0, Matrix::<char> { rows: 1, columns: 3, data: vec!['a', 'b', 'c'].into_boxed_slice() })]
+    #[case(1, Matrix::<char> { rows: 3, columns: 5, data: "......abc......".chars().collect() })]
+    #[case(2, Matrix::<char> { rows: 5, columns: 7, data: "................abc................".chars().collect() })]
+    fn pad(#[case] n: usize, #[case] expected: Matrix<char>) {
+        let mut m = Matrix::<char>::from_vec(vec!['a', 'b', 'c'], 1, 3).unwrap();
+        m.pad('.', n);
+        assert_eq!(expected, m);
+    }
+
+    #[rstest]
+    #[case(2, 3, vec![1, 2, 3, 4, 5, 6])]
+    #[case(3, 4, vec![1, 2, 3, 0, 4, 5, 6, 0, 0, 0, 0, 0])]
+    #[case(1, 2, vec![1, 2])]
+    #[case(4, 1, vec![1, 4, 0, 0])]
+    #[case(0, 5, vec![])]
+    fn resize(#[case] rows: usize, #[case] columns: usize, #[case] expected: Vec<i64>) {
+        let mut m = Matrix::<i64>::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+        m.resize(rows, columns, 0);
+        assert_eq!(Matrix::from_vec(expected, rows, columns).unwrap(), m);
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::{Error, Matrix};
+
+impl<T> Matrix<T> {
+    /// Builds a matrix from a rectangular block of text, converting every character with `f`.
+    ///
+    /// Rows with a different length than the first one are reported as [`Error::RaggedRow`].
+    pub fn from_text(text: &str, f: impl Fn(char) -> T) -> Result<Self, Error> {
+        let mut data = Vec::new();
+        let mut rows = 0usize;
+        let mut columns = 0usize;
+        for (row, line) in text.lines().enumerate() {
+            let before = data.len();
+            data.extend(line.chars().map(&f));
+            let actual = data.len() - before;
+            if row == 0 {
+                columns = actual;
+            } else if actual != columns {
+                return Err(Error::RaggedRow {
+                    row,
+                    expected: columns,
+                    actual,
+                });
+            }
+            rows += 1;
+        }
+        Self::from_vec(data, rows, columns)
+    }
+
+    /// Renders one line per row, converting every cell with `f`.
+    pub fn render_with(&self, f: impl Fn(&T) -> char) -> String {
+        let mut text = String::with_capacity(self.rows * (self.columns + 1));
+        for row in self.rows() {
+            text.extend(row.iter().map(&f));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl FromStr for Matrix<char> {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_text(s, |c| c)
+    }
+}
+
+/// Only accepts ASCII input, other characters are reported as [`Error::NonAscii`].
+impl FromStr for Matrix<u8> {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for (row, line) in s.lines().enumerate() {
+            let mut characters = line.chars().enumerate();
+            if let Some((column, character)) = characters.find(|(_, c)| !c.is_ascii()) {
+                return Err(Error::NonAscii {
+                    row,
+                    column,
+                    character,
+                });
+            }
+        }
+        Self::from_text(s, |c| c as u8)
+    }
+}
+
+impl Display for Matrix<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render_with(|c| *c))
+    }
+}
+
+impl Display for Matrix<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render_with(|b| *b as char))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Error, Matrix};
+
+    #[rstest]
+    #[case("ab\ncd\nef", Ok(Matrix::from_vec(vec!['a', 'b', 'c', 'd', 'e', 'f'], 3, 2).unwrap()))]
+    #[case("#.#\n", Ok(Matrix::from_vec(vec!['#', '.', '#'], 1, 3).unwrap()))]
+    #[case("", Ok(Matrix::from_vec(vec![], 0, 0).unwrap()))]
+    #[case("abc\nde\nfgh", Err(Error::RaggedRow { row: 1, expected: 3, actual: 2 }))]
+    #[case("ab\ncd\nefg", Err(Error::RaggedRow { row: 2, expected: 2, actual: 3 }))]
+    fn parse_char(#[case] input: &str, #[case] expected: Result<Matrix<char>, Error>) {
+        assert_eq!(expected, input.parse());
+    }
+
+    #[rstest]
+    #[case("12\n34", Ok(Matrix::from_vec(vec![b'1', b'2', b'3', b'4'], 2, 2).unwrap()))]
+    #[case("..\n.█", Err(Error::NonAscii { row: 1, column: 1, character: '█' }))]
+    #[case("é\n.", Err(Error::NonAscii { row: 0, column: 0, character: 'é' }))]
+    fn parse_u8(#[case] input: &str, #[case] expected: Result<Matrix<u8>, Error>) {
+        assert_eq!(expected, input.parse());
+    }
+
+    #[rstest]
+    #[case("..#\n#..\n")]
+    #[case("MMMS\nMSAM\n")]
+    fn round_trip(#[case] input: &str) {
+        assert_eq!(input, input.parse::<Matrix<char>>().unwrap().to_string());
+        assert_eq!(input, input.parse::<Matrix<u8>>().unwrap().to_string());
+    }
+
+    #[test]
+    fn render_with() {
+        let m = Matrix::from_text("0123\n4560", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(
+            "#..#\n..##\n",
+            m.render_with(|x| if x % 3 == 0 { '#' } else { '.' })
+        );
+    }
+
+    #[test]
+    fn ragged_row_message() {
+        assert_eq!(
+            "row 4 has 7 cells but 5 were expected",
+            Error::RaggedRow {
+                row: 4,
+                expected: 5,
+                actual: 7
+            }
+            .to_string()
+        );
+    }
+}
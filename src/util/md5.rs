@@ -0,0 +1,269 @@
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Per-round shift amounts.
+const SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9,
+    14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15,
+    21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// Integer parts of `abs(sin(i + 1)) * 2^32`.
+const CONSTANTS: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+const INITIAL_STATE: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+/// 128 bit MD5 hash.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Digest(pub [u8; 16]);
+
+impl Digest {
+    /// Number of zeros at the start of the hex representation.
+    pub fn leading_zero_nibbles(&self) -> u32 {
+        u128::from_be_bytes(self.0).leading_zeros() / 4
+    }
+
+    /// Whether the hex representation starts with at least `count` zeros, without formatting it.
+    pub fn has_leading_zeros(&self, count: u32) -> bool {
+        self.leading_zero_nibbles() >= count
+    }
+
+    /// Lowercase hex digits, e.g. for hashing the hash again.
+    pub fn to_hex(self) -> String {
+        self.to_string()
+    }
+}
+
+impl std::fmt::Display for Digest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+/// Streaming hasher, data can be fed in any number of pieces. Cloning it after feeding a common
+/// prefix saves hashing that prefix again.
+#[derive(Clone, Debug)]
+pub struct Md5 {
+    state: [u32; 4],
+    buffer: [u8; 64],
+    buffered: usize,
+    length: u64,
+}
+
+impl Default for Md5 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Md5 {
+    pub fn new() -> Self {
+        Self {
+            state: INITIAL_STATE,
+            buffer: [0; 64],
+            buffered: 0,
+            length: 0,
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.length = self.length.wrapping_add(data.len() as u64);
+        let mut data = data;
+        if self.buffered > 0 {
+            let taken = data.len().min(64 - self.buffered);
+            self.buffer[self.buffered..self.buffered + taken].copy_from_slice(&data[..taken]);
+            self.buffered += taken;
+            data = &data[taken..];
+            if self.buffered < 64 {
+                return;
+            }
+            let block = self.buffer;
+            self.compress(&block);
+            self.buffered = 0;
+        }
+        let mut blocks = data.chunks_exact(64);
+        for block in &mut blocks {
+            self.compress(block.try_into().unwrap());
+        }
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffered = rest.len();
+    }
+
+    pub fn finalize(mut self) -> Digest {
+        let bit_length = self.length.wrapping_mul(8);
+        // a single one bit, then zeros up to 8 bytes short of a block boundary
+        let padding_length = (55usize.wrapping_sub(self.buffered) % 64) + 1;
+        let mut padding = [0u8; 64];
+        padding[0] = 0x80;
+        self.update(&padding[..padding_length]);
+        self.update(&bit_length.to_le_bytes());
+        let mut digest = [0u8; 16];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        Digest(digest)
+    }
+
+    fn compress(&mut self, block: &[u8; 64]) {
+        let mut words = [0u32; 16];
+        for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+        let [mut a, mut b, mut c, mut d] = self.state;
+        for i in 0..64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+            let rotated = a
+                .wrapping_add(f)
+                .wrapping_add(CONSTANTS[i])
+                .wrapping_add(words[g])
+                .rotate_left(SHIFTS[i]);
+            (a, b, c, d) = (d, b.wrapping_add(rotated), b, c);
+        }
+        for (state, value) in self.state.iter_mut().zip([a, b, c, d]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+}
+
+pub fn md5(data: &[u8]) -> Digest {
+    let mut hasher = Md5::new();
+    hasher.update(data);
+    hasher.finalize()
+}
+
+/// Nonces handed to a thread at once.
+const BATCH_SIZE: u64 = 1024;
+
+/// Smallest nonce in `nonces` for which the hash of `prefix` followed by the decimal nonce
+/// satisfies `predicate`, searched on all available cores.
+pub fn find_nonce(
+    prefix: &[u8],
+    nonces: Range<u64>,
+    predicate: impl Fn(&Digest) -> bool + Sync,
+) -> Option<u64> {
+    let mut prefix_hasher = Md5::new();
+    prefix_hasher.update(prefix);
+    let next_batch = AtomicU64::new(nonces.start);
+    let found = AtomicU64::new(u64::MAX);
+    let search = || loop {
+        let start = next_batch.fetch_add(BATCH_SIZE, Ordering::Relaxed);
+        // batches are handed out in order, so later ones cannot hold a smaller nonce
+        if start >= nonces.end || start > found.load(Ordering::Relaxed) {
+            return;
+        }
+        for nonce in start..start.saturating_add(BATCH_SIZE).min(nonces.end) {
+            let mut hasher = prefix_hasher.clone();
+            hasher.update(nonce.to_string().as_bytes());
+            if predicate(&hasher.finalize()) {
+                found.fetch_min(nonce, Ordering::Relaxed);
+                break;
+            }
+        }
+    };
+    let thread_count = std::thread::available_parallelism().map_or(1, |n| n.get());
+    std::thread::scope(|scope| {
+        for _ in 1..thread_count {
+            scope.spawn(search);
+        }
+        search();
+    });
+    match found.into_inner() {
+        u64::MAX => None,
+        nonce => Some(nonce),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{find_nonce, md5, Digest, Md5};
+
+    #[rstest]
+    #[case("", "d41d8cd98f00b204e9800998ecf8427e")]
+    #[case("a", "0cc175b9c0f1b6a831c399e269772661")]
+    #[case("abc", "900150983cd24fb0d6963f7d28e17f72")]
+    #[case("message digest", "f96b697d7cb7938d525a2f31aaf161d0")]
+    #[case("abcdefghijklmnopqrstuvwxyz", "c3fcd3d76192e4007dfb496cca67e13b")]
+    #[case(
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+        "d174ab98d277d9f5a5611c2c9f419d9f"
+    )]
+    #[case(
+        "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+        "57edf4a22be3c955ac49da2e2107b67a"
+    )]
+    fn rfc_1321_test_suite(#[case] message: &str, #[case] expected: &str) {
+        assert_eq!(expected, md5(message.as_bytes()).to_hex());
+    }
+
+    #[rstest]
+    #[case(55)]
+    #[case(56)]
+    #[case(63)]
+    #[case(64)]
+    #[case(65)]
+    #[case(200)]
+    fn streaming_matches_one_shot(#[case] length: usize) {
+        let data = (0..length).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        for piece in [1, 3, 64, 100] {
+            let mut hasher = Md5::new();
+            data.chunks(piece).for_each(|chunk| hasher.update(chunk));
+            assert_eq!(md5(&data), hasher.finalize());
+        }
+    }
+
+    #[rstest]
+    #[case([0; 16], 32)]
+    #[case([0x00, 0x00, 0x0f, 0xff, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 5)]
+    #[case([0x00, 0x00, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 4)]
+    #[case([0xf0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 0)]
+    fn leading_zeros(#[case] bytes: [u8; 16], #[case] expected: u32) {
+        let digest = Digest(bytes);
+        assert_eq!(expected, digest.leading_zero_nibbles());
+        assert!(digest.has_leading_zeros(expected));
+        assert!(!digest.has_leading_zeros(expected + 1));
+        let hex = digest.to_hex();
+        assert_eq!(
+            expected as usize,
+            hex.len() - hex.trim_start_matches('0').len()
+        );
+    }
+
+    #[test]
+    fn mine_advent_coins() {
+        // examples of 2015 day 4, starting close to the answers to keep the test fast
+        assert_eq!(
+            Some(609043),
+            find_nonce(b"abcdef", 600_000..700_000, |d| d.has_leading_zeros(5))
+        );
+        assert_eq!(
+            Some(1048970),
+            find_nonce(b"pqrstuv", 1_040_000..1_100_000, |d| d.has_leading_zeros(5))
+        );
+        assert!(md5(b"abcdef609043").to_hex().starts_with("000001dbbfa"));
+    }
+
+    #[test]
+    fn smallest_nonce_wins() {
+        let predicate = |d: &Digest| d.has_leading_zeros(2);
+        let expected = (0..100_000).find(|n| predicate(&md5(format!("abc{}", n).as_bytes())));
+        assert_eq!(expected, find_nonce(b"abc", 0..100_000, predicate));
+        assert_eq!(None, find_nonce(b"abc", 0..10, |d| d.has_leading_zeros(32)));
+    }
+}
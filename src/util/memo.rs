@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+/// Makes every [`Memo`] print its [`Stats`] to stderr once it is dropped.
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    /// Calls answered from the cache.
+    pub hits: usize,
+    /// Calls that had to evaluate the function.
+    pub misses: usize,
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} hits, {} misses", self.hits, self.misses)
+    }
+}
+
+struct Cache<A, R> {
+    values: HashMap<A, R>,
+    stats: Stats,
+}
+
+type Function<'f, A, R> = dyn Fn(&mut Handle<'_, A, R>, A) -> R + 'f;
+
+/// Recursive function whose results are cached by argument. The function recurses through the
+/// [`Handle`] it is given instead of calling itself, so inner calls hit the same cache.
+pub struct Memo<'f, A, R> {
+    cache: Cache<A, R>,
+    function: Box<Function<'f, A, R>>,
+}
+
+impl<'f, A: Clone + Eq + Hash, R: Clone> Memo<'f, A, R> {
+    pub fn new(function: impl Fn(&mut Handle<'_, A, R>, A) -> R + 'f) -> Self {
+        Self {
+            cache: Cache {
+                values: HashMap::new(),
+                stats: Stats::default(),
+            },
+            function: Box::new(function),
+        }
+    }
+
+    pub fn get(&mut self, argument: A) -> R {
+        Handle {
+            cache: &mut self.cache,
+            function: &*self.function,
+        }
+        .get(argument)
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.cache.stats
+    }
+
+    /// Number of cached results.
+    pub fn len(&self) -> usize {
+        self.cache.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.values.is_empty()
+    }
+}
+
+impl<A, R> Drop for Memo<'_, A, R> {
+    fn drop(&mut self) {
+        if VERBOSE.load(Ordering::Relaxed) {
+            eprintln!("memo: {}", self.cache.stats);
+        }
+    }
+}
+
+/// Access to the cache from within the memoised function.
+pub struct Handle<'a, A, R> {
+    cache: &'a mut Cache<A, R>,
+    function: &'a Function<'a, A, R>,
+}
+
+impl<A: Clone + Eq + Hash, R: Clone> Handle<'_, A, R> {
+    pub fn get(&mut self, argument: A) -> R {
+        if let Some(value) = self.cache.values.get(&argument) {
+            self.cache.stats.hits += 1;
+            return value.clone();
+        }
+        self.cache.stats.misses += 1;
+        let value = (self.function)(
+            &mut Handle {
+                cache: self.cache,
+                function: self.function,
+            },
+            argument.clone(),
+        );
+        self.cache.values.insert(argument, value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Memo, Stats};
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 1)]
+    #[case(10, 55)]
+    #[case(90, 2880067194370816120)]
+    fn fibonacci(#[case] n: u64, #[case] expected: u64) {
+        let mut fibonacci = Memo::new(|fib, n: u64| match n {
+            0 | 1 => n,
+            _ => fib.get(n - 1) + fib.get(n - 2),
+        });
+        assert_eq!(expected, fibonacci.get(n));
+        assert_eq!(fibonacci.len(), fibonacci.stats().misses);
+    }
+
+    #[test]
+    fn stats_across_calls() {
+        let mut fibonacci = Memo::new(|fib, n: u64| match n {
+            0 | 1 => n,
+            _ => fib.get(n - 1) + fib.get(n - 2),
+        });
+        fibonacci.get(5);
+        assert_eq!(Stats { hits: 3, misses: 6 }, fibonacci.stats());
+        fibonacci.get(5);
+        assert_eq!(Stats { hits: 4, misses: 6 }, fibonacci.stats());
+        assert_eq!(6, fibonacci.len());
+        assert_eq!("4 hits, 6 misses", fibonacci.stats().to_string());
+    }
+
+    #[test]
+    fn count_stones_after_blinks() {
+        // stones split into their digit halves when even in length, see 2024 day 11
+        let mut count = Memo::new(|count, (stone, blinks): (u64, u32)| {
+            if blinks == 0 {
+                return 1u64;
+            }
+            let digits = stone.checked_ilog10().unwrap_or(0) + 1;
+            match stone {
+                0 => count.get((1, blinks - 1)),
+                _ if digits % 2 == 0 => {
+                    let shift = 10u64.pow(digits / 2);
+                    count.get((stone / shift, blinks - 1)) + count.get((stone % shift, blinks - 1))
+                }
+                _ => count.get((stone * 2024, blinks - 1)),
+            }
+        });
+        assert_eq!(55312u64, [125, 17].map(|s| count.get((s, 25))).iter().sum());
+    }
+
+    #[test]
+    fn borrows_environment() {
+        let patterns = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+        let mut arrangements = Memo::new(|arrangements, design: &str| -> usize {
+            match design.is_empty() {
+                true => 1,
+                false => patterns
+                    .iter()
+                    .filter_map(|p| design.strip_prefix(p))
+                    .map(|rest| arrangements.get(rest))
+                    .sum(),
+            }
+        });
+        assert_eq!(2, arrangements.get("brwrr"));
+        assert_eq!(6, arrangements.get("rrbgbr"));
+        assert_eq!(0, arrangements.get("bbrgwb"));
+    }
+}
@@ -1,3 +1,29 @@
+// The helpers form a toolbox for upcoming puzzles, so not every item has a caller yet.
+#![allow(dead_code)]
+
+pub mod bigint;
+pub mod bitset;
+pub mod combinatorics;
+pub mod cycle;
+pub mod direction;
+pub mod disjoint_set;
+pub mod geometry;
+pub mod graph;
+pub mod hex;
+pub mod interval;
+pub mod knot_hash;
+pub mod line;
 pub mod matrix;
+pub mod md5;
+pub mod memo;
+pub mod num;
+pub mod number_theory;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod point_n;
 pub mod position;
+pub mod rational;
+pub mod search;
 pub mod size;
+pub mod sparse_grid;
@@ -0,0 +1,140 @@
+use super::{Digits, Numeric};
+
+/// Arithmetic that reports overflow and impossible operations as `None`, implemented for all
+/// primitive integers so generic code can use it.
+pub trait CheckedOp: Sized {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exponent: u32) -> Option<Self>;
+    /// Divides only if there is no remainder, e.g. `12 / 4` but not `12 / 5`.
+    fn checked_div_exact(self, rhs: Self) -> Option<Self>;
+    /// Appends the decimal digits of `rhs`, e.g. `12 || 345 = 12345`. Negative `rhs` values
+    /// have no digits to append.
+    fn concat(self, rhs: Self) -> Option<Self>;
+    /// Inverse of [`CheckedOp::concat`], stripping the decimal digits of `suffix` from the end,
+    /// e.g. `12345` without `45` is `123`. `None` if `self` does not end with `suffix` or no
+    /// digits would remain.
+    fn checked_unconcat(self, suffix: Self) -> Option<Self>;
+}
+
+macro_rules! checkedop_impl {
+    ($integer_type:ty) => {
+        impl CheckedOp for $integer_type {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.checked_sub(rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                self.checked_mul(rhs)
+            }
+            fn checked_pow(self, exponent: u32) -> Option<Self> {
+                self.checked_pow(exponent)
+            }
+            fn checked_div_exact(self, rhs: Self) -> Option<Self> {
+                match self.checked_rem(rhs)? {
+                    0 => self.checked_div(rhs),
+                    _ => None,
+                }
+            }
+            fn concat(self, rhs: Self) -> Option<Self> {
+                if rhs.is_negative() {
+                    return None;
+                }
+                match (10 as $integer_type).checked_pow(rhs.digit_count()) {
+                    Some(shift) if self.is_negative() => self.checked_mul(shift)?.checked_sub(rhs),
+                    Some(shift) => self.checked_mul(shift)?.checked_add(rhs),
+                    // only a leading zero can be prepended to the widest values
+                    None => (self == 0).then_some(rhs),
+                }
+            }
+            fn checked_unconcat(self, suffix: Self) -> Option<Self> {
+                if suffix.is_negative() {
+                    return None;
+                }
+                let shift = (10 as $integer_type).checked_pow(suffix.digit_count())?;
+                let (prefix, rest) = (self / shift, self % shift);
+                (prefix != 0 && rest.abs_diff(0) == suffix.abs_diff(0)).then_some(prefix)
+            }
+        }
+    };
+}
+
+checkedop_impl!(u8);
+checkedop_impl!(u16);
+checkedop_impl!(u32);
+checkedop_impl!(u64);
+checkedop_impl!(u128);
+checkedop_impl!(usize);
+checkedop_impl!(i8);
+checkedop_impl!(i16);
+checkedop_impl!(i32);
+checkedop_impl!(i64);
+checkedop_impl!(i128);
+checkedop_impl!(isize);
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use std::fmt::Debug;
+
+    use super::CheckedOp;
+
+    #[rstest]
+    #[case(1u32, 2u32, Some(12u32))]
+    #[case(56u8, 1u8, None)]
+    #[case(809u32, 99u32, Some(80999u32))]
+    #[case(0u8, 200u8, Some(200u8))]
+    #[case(12u64, 0u64, Some(120u64))]
+    #[case(-12i32, 3i32, Some(-123i32))]
+    #[case(12i32, -3i32, None)]
+    fn interger_concatinate<T: CheckedOp + Debug + PartialEq>(
+        #[case] a: T,
+        #[case] b: T,
+        #[case] expected: Option<T>,
+    ) {
+        assert_eq!(expected, a.concat(b))
+    }
+
+    #[rstest]
+    #[case(12345u32, 45u32, Some(123u32))]
+    #[case(12345u32, 4u32, None)]
+    #[case(1005u32, 5u32, Some(100u32))]
+    #[case(120u32, 0u32, Some(12u32))]
+    #[case(5u32, 5u32, None)]
+    #[case(255u8, 255u8, None)]
+    #[case(-123i64, 23i64, Some(-1i64))]
+    #[case(123i64, -23i64, None)]
+    fn unconcat<T: CheckedOp + Copy + Debug + PartialEq>(
+        #[case] a: T,
+        #[case] suffix: T,
+        #[case] expected: Option<T>,
+    ) {
+        assert_eq!(expected, a.checked_unconcat(suffix));
+        if let Some(prefix) = expected {
+            assert_eq!(Some(a), prefix.concat(suffix));
+        }
+    }
+
+    #[rstest]
+    #[case(12i16, 4i16, Some(3i16))]
+    #[case(12i16, 5i16, None)]
+    #[case(-12i16, 4i16, Some(-3i16))]
+    #[case(12i16, 0i16, None)]
+    #[case(i16::MIN, -1i16, None)]
+    fn div_exact(#[case] a: i16, #[case] b: i16, #[case] expected: Option<i16>) {
+        assert_eq!(expected, CheckedOp::checked_div_exact(a, b));
+    }
+
+    #[test]
+    fn generic_arithmetic() {
+        fn power_sum<T: CheckedOp + Copy>(a: T, b: T, exponent: u32) -> Option<T> {
+            CheckedOp::checked_add(a.checked_pow(exponent)?, b.checked_pow(exponent)?)
+        }
+        assert_eq!(Some(25u8), power_sum(3u8, 4u8, 2));
+        assert_eq!(None, power_sum(3u8, 4u8, 4));
+        assert_eq!(Some(-7i8), CheckedOp::checked_sub(-3i8, 4));
+    }
+}
@@ -0,0 +1,106 @@
+/// Decimal digits of primitive integers. Negative values are treated by their magnitude, the
+/// sign being kept on every part when splitting.
+pub trait Digits: Sized {
+    /// Number of decimal digits, zero having one.
+    fn digit_count(self) -> u32;
+    /// Decimal digits from the most significant one.
+    fn digits(self) -> impl Iterator<Item = u8>;
+    /// Splits into the number formed by the first `mid` digits and the one formed by the
+    /// rest, e.g. `1234` at `1` into `1` and `234`. `None` if there are fewer than `mid` digits.
+    fn split_digits_at(self, mid: u32) -> Option<(Self, Self)>;
+}
+
+macro_rules! digits_impl {
+    ($integer_type:ty, $unsigned:ty) => {
+        impl Digits for $integer_type {
+            fn digit_count(self) -> u32 {
+                self.abs_diff(0).checked_ilog10().map_or(1, |log| log + 1)
+            }
+
+            fn digits(self) -> impl Iterator<Item = u8> {
+                let magnitude = self.abs_diff(0);
+                let mut divisor = (10 as $unsigned).pow(self.digit_count() - 1);
+                std::iter::from_fn(move || {
+                    if divisor == 0 {
+                        return None;
+                    }
+                    let digit = (magnitude / divisor % 10) as u8;
+                    divisor /= 10;
+                    Some(digit)
+                })
+            }
+
+            fn split_digits_at(self, mid: u32) -> Option<(Self, Self)> {
+                let trailing_digits = self.digit_count().checked_sub(mid)?;
+                Some(match (10 as $integer_type).checked_pow(trailing_digits) {
+                    Some(shift) => (self / shift, self % shift),
+                    None => (0, self),
+                })
+            }
+        }
+    };
+}
+
+digits_impl!(u8, u8);
+digits_impl!(u16, u16);
+digits_impl!(u32, u32);
+digits_impl!(u64, u64);
+digits_impl!(u128, u128);
+digits_impl!(usize, usize);
+digits_impl!(i8, u8);
+digits_impl!(i16, u16);
+digits_impl!(i32, u32);
+digits_impl!(i64, u64);
+digits_impl!(i128, u128);
+digits_impl!(isize, usize);
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Digits;
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(9, 1)]
+    #[case(10, 2)]
+    #[case(-10, 2)]
+    #[case(i64::MAX, 19)]
+    #[case(i64::MIN, 19)]
+    fn digit_count(#[case] value: i64, #[case] expected: u32) {
+        assert_eq!(expected, value.digit_count());
+    }
+
+    #[rstest]
+    #[case(0, vec![0])]
+    #[case(1203, vec![1, 2, 0, 3])]
+    #[case(-45, vec![4, 5])]
+    #[case(i32::MIN, vec![2, 1, 4, 7, 4, 8, 3, 6, 4, 8])]
+    fn digits(#[case] value: i32, #[case] expected: Vec<u8>) {
+        assert_eq!(expected, value.digits().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn digits_of_widest_unsigned() {
+        assert_eq!(39, u128::MAX.digits().count());
+        assert_eq!(Some(5), u128::MAX.digits().last());
+        assert_eq!(vec![2, 5, 5], 255u8.digits().collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case(1234, 2, Some((12, 34)))]
+    #[case(253000, 3, Some((253, 0)))]
+    #[case(1234, 0, Some((0, 1234)))]
+    #[case(1234, 4, Some((1234, 0)))]
+    #[case(1234, 5, None)]
+    #[case(-1234, 1, Some((-1, -234)))]
+    fn split_digits(#[case] value: i32, #[case] mid: u32, #[case] expected: Option<(i32, i32)>) {
+        assert_eq!(expected, value.split_digits_at(mid));
+    }
+
+    #[test]
+    fn split_digits_without_room_for_shift() {
+        assert_eq!(Some((0, 255)), 255u8.split_digits_at(0));
+        assert_eq!(Some((2, 55)), 255u8.split_digits_at(1));
+    }
+}
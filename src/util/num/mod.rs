@@ -0,0 +1,9 @@
+mod checked_op;
+mod digits;
+mod numeric;
+mod signed_diff;
+
+pub use checked_op::CheckedOp;
+pub use digits::Digits;
+pub use numeric::Numeric;
+pub use signed_diff::SignedDiff;
@@ -0,0 +1,55 @@
+use std::ops::{Add, Mul, Sub};
+
+/// Number types the generic helpers work with, e.g. matrix entries or gcd arguments.
+pub trait Numeric:
+    Copy + PartialEq + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Whether the value is below zero. The inherent `is_negative` of signed primitives shadows
+    /// this one in method calls, with the same result.
+    fn is_negative(&self) -> bool {
+        *self < Self::ZERO
+    }
+}
+
+macro_rules! numeric_impl {
+    ($number_type:ty, $zero:expr, $one:expr) => {
+        impl Numeric for $number_type {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+        }
+    };
+}
+
+numeric_impl!(u8, 0, 1);
+numeric_impl!(u16, 0, 1);
+numeric_impl!(u32, 0, 1);
+numeric_impl!(u64, 0, 1);
+numeric_impl!(u128, 0, 1);
+numeric_impl!(usize, 0, 1);
+numeric_impl!(i8, 0, 1);
+numeric_impl!(i16, 0, 1);
+numeric_impl!(i32, 0, 1);
+numeric_impl!(i64, 0, 1);
+numeric_impl!(i128, 0, 1);
+numeric_impl!(isize, 0, 1);
+numeric_impl!(f32, 0.0, 1.0);
+numeric_impl!(f64, 0.0, 1.0);
+
+#[cfg(test)]
+mod tests {
+    use super::Numeric;
+    use crate::util::rational::Rational;
+
+    #[test]
+    fn negative_values() {
+        assert!(!Numeric::is_negative(&0u8));
+        assert!(Numeric::is_negative(&-3i64));
+        assert!(Numeric::is_negative(&-0.5f64));
+        assert!(!Numeric::is_negative(&0.0f32));
+        assert!(Numeric::is_negative(&Rational::new(-1, 2).unwrap()));
+        assert!(!Numeric::is_negative(&Rational::ZERO));
+    }
+}
@@ -27,7 +27,7 @@ signed_diff_impl!(u128, i128);
 
 #[cfg(test)]
 mod tests {
-    use crate::year_2024::day08::signed_diff::SignedDiff;
+    use super::SignedDiff;
 
     macro_rules! signed_diff_test {
         ($unsigned:ty, $signed:ty) => {
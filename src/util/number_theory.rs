@@ -0,0 +1,311 @@
+use super::num::Numeric;
+
+/// Greatest common divisor and least common multiple of primitive integers. The results are
+/// never negative; `gcd(0, 0)` is zero and so is every least common multiple involving zero.
+pub trait Gcd: Numeric {
+    /// Returns `None` if the result does not fit, e.g. for `i32::MIN.checked_gcd(0)`.
+    fn checked_gcd(self, other: Self) -> Option<Self>;
+
+    fn checked_lcm(self, other: Self) -> Option<Self>;
+
+    /// Panics if the result does not fit, like the arithmetic operators do in debug builds.
+    fn gcd(self, other: Self) -> Self {
+        self.checked_gcd(other)
+            .expect("attempt to compute gcd with overflow")
+    }
+
+    fn lcm(self, other: Self) -> Self {
+        self.checked_lcm(other)
+            .expect("attempt to compute lcm with overflow")
+    }
+}
+
+macro_rules! gcd_impl {
+    ($t:ty, $unsigned:ty) => {
+        impl Gcd for $t {
+            fn checked_gcd(self, other: Self) -> Option<Self> {
+                let (mut a, mut b) = (self.abs_diff(0), other.abs_diff(0));
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                Self::try_from(a).ok()
+            }
+
+            fn checked_lcm(self, other: Self) -> Option<Self> {
+                if self == 0 || other == 0 {
+                    return Some(0);
+                }
+                let divisor = self.abs_diff(0) / self.checked_gcd(other)?.abs_diff(0);
+                let multiple: $unsigned = divisor.checked_mul(other.abs_diff(0))?;
+                Self::try_from(multiple).ok()
+            }
+        }
+    };
+}
+
+gcd_impl!(i8, u8);
+gcd_impl!(i16, u16);
+gcd_impl!(i32, u32);
+gcd_impl!(i64, u64);
+gcd_impl!(i128, u128);
+gcd_impl!(isize, usize);
+gcd_impl!(u8, u8);
+gcd_impl!(u16, u16);
+gcd_impl!(u32, u32);
+gcd_impl!(u64, u64);
+gcd_impl!(u128, u128);
+gcd_impl!(usize, usize);
+
+/// Greatest common divisor of all values, zero for none.
+pub fn checked_gcd_all<T: Gcd>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ZERO, |acc, v| acc.checked_gcd(v))
+}
+
+/// Least common multiple of all values, one for none. E.g. the first step at which several
+/// independent cycles all line up.
+pub fn checked_lcm_all<T: Gcd>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |acc, v| acc.checked_lcm(v))
+}
+
+/// Extended Euclidean algorithm, returning `(g, x, y)` with `a * x + b * y = g` where `g` is
+/// the non-negative greatest common divisor of `a` and `b`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    let (mut old_t, mut t) = (0i128, 1i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+        (old_t, t) = (t, old_t - quotient * t);
+    }
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// `(a * b) mod modulus` in `0..modulus` without overflowing, even for moduli beyond `2^63`.
+pub fn mod_mul(a: i128, b: i128, modulus: i128) -> i128 {
+    let (mut a, mut b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    // double and add, every intermediate staying below 2 * modulus
+    let mut result = 0i128;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = ((result as u128 + a as u128) % modulus as u128) as i128;
+        }
+        a = ((a as u128 * 2) % modulus as u128) as i128;
+        b >>= 1;
+    }
+    result
+}
+
+/// `base^exponent mod modulus` in `0..modulus` by repeated squaring. Panics for a
+/// non-positive modulus.
+pub fn mod_pow(base: i128, exponent: u128, modulus: i128) -> i128 {
+    assert!(modulus > 0, "modulus must be positive");
+    let mut result = 1 % modulus;
+    let mut base = base.rem_euclid(modulus);
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// The `x` in `0..modulus` with `a * x = 1 (mod modulus)`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves a system of congruences `x = residue (mod modulus)` with the Chinese Remainder
+/// Theorem, returning the smallest non-negative solution and the modulus it repeats with.
+///
+/// The moduli need not be coprime. Returns `None` if the congruences contradict each other,
+/// a modulus is not positive or the combined modulus does not fit into an `i128`.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0i128, 1i128), |(x, m), (residue, modulus)| {
+            if modulus <= 0 {
+                return None;
+            }
+            let residue = residue.rem_euclid(modulus);
+            let (g, p, _) = extended_gcd(m, modulus);
+            let difference = residue.checked_sub(x)?;
+            if difference % g != 0 {
+                return None;
+            }
+            let combined = (m / g).checked_mul(modulus)?;
+            // x + m * t solves both when t = (difference / g) * p (mod modulus / g)
+            let t = mod_mul(difference / g, p, modulus / g);
+            let offset = mod_mul(m, t, combined);
+            // both terms are below the combined modulus, so an overflowing sum exceeds it
+            let solution = match x.checked_add(offset) {
+                Some(sum) => sum % combined,
+                None => offset - (combined - x),
+            };
+            Some((solution, combined))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{
+        checked_gcd_all, checked_lcm_all, crt, extended_gcd, mod_inverse, mod_mul, mod_pow, Gcd,
+    };
+
+    #[rstest]
+    #[case(12, 18, Some(6), Some(36))]
+    #[case(-12, 18, Some(6), Some(36))]
+    #[case(0, 7, Some(7), Some(0))]
+    #[case(0, 0, Some(0), Some(0))]
+    #[case(i32::MIN, 0, None, Some(0))]
+    #[case(i32::MIN, 6, Some(2), None)]
+    #[case(65536, 65537, Some(1), None)]
+    fn checked_i32(
+        #[case] a: i32,
+        #[case] b: i32,
+        #[case] gcd: Option<i32>,
+        #[case] lcm: Option<i32>,
+    ) {
+        assert_eq!(gcd, a.checked_gcd(b));
+        assert_eq!(gcd, b.checked_gcd(a));
+        assert_eq!(lcm, a.checked_lcm(b));
+    }
+
+    #[test]
+    fn unsigned() {
+        assert_eq!(4u8, 200u8.gcd(12));
+        assert_eq!(Some(255u8), 15u8.checked_lcm(17));
+        assert_eq!(None, 16u8.checked_lcm(17));
+        assert_eq!(u128::MAX, u128::MAX.gcd(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn gcd_overflow_panics() {
+        i64::MIN.gcd(i64::MIN);
+    }
+
+    #[rstest]
+    #[case(vec![], Some(0), Some(1))]
+    #[case(vec![4], Some(4), Some(4))]
+    #[case(vec![-4], Some(4), Some(4))]
+    #[case(vec![12, 18, 30], Some(6), Some(180))]
+    #[case(vec![19, 13, 59, 31, 7], Some(1), Some(3162341))]
+    #[case(vec![i64::MAX, 2], Some(1), None)]
+    fn all(#[case] values: Vec<i64>, #[case] gcd: Option<i64>, #[case] lcm: Option<i64>) {
+        assert_eq!(gcd, checked_gcd_all(values.clone()));
+        assert_eq!(lcm, checked_lcm_all(values));
+    }
+
+    #[rstest]
+    #[case(240, 46)]
+    #[case(-240, 46)]
+    #[case(17, -5)]
+    #[case(0, 9)]
+    #[case(0, 0)]
+    fn extended_euclid(#[case] a: i128, #[case] b: i128) {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(a.gcd(b), g);
+        assert_eq!(g, a * x + b * y);
+    }
+
+    #[rstest]
+    #[case(4, 13, 497, 445)]
+    #[case(2, 0, 7, 1)]
+    #[case(5, 3, 1, 0)]
+    #[case(-2, 3, 7, 6)]
+    #[case(3, 1_000_000_006, 1_000_000_007, 1)]
+    #[case(2, 127, i128::MAX, 1)]
+    fn modular_pow(
+        #[case] base: i128,
+        #[case] exponent: u128,
+        #[case] modulus: i128,
+        #[case] expected: i128,
+    ) {
+        assert_eq!(expected, mod_pow(base, exponent, modulus));
+    }
+
+    #[test]
+    fn modular_mul_without_overflow() {
+        let modulus = (1i128 << 100) + 7;
+        let a = (1i128 << 99) + 3;
+        // 2a = 2^100 + 6 = modulus - 1
+        assert_eq!(modulus - 1, mod_mul(a, 2, modulus));
+        assert_eq!(1, mod_mul(modulus - 1, modulus - 1, modulus));
+    }
+
+    #[rstest]
+    #[case(3, 11, Some(4))]
+    #[case(-3, 11, Some(7))]
+    #[case(10, 17, Some(12))]
+    #[case(6, 9, None)]
+    #[case(5, 0, None)]
+    fn modular_inverse(#[case] a: i128, #[case] modulus: i128, #[case] expected: Option<i128>) {
+        assert_eq!(expected, mod_inverse(a, modulus));
+    }
+
+    #[rstest]
+    #[case(vec![(2, 3), (3, 5), (2, 7)], Some((23, 105)))]
+    #[case(vec![(0, 17), (-2, 13), (-3, 19)], Some((3417, 4199)))]
+    #[case(vec![(2, 4), (4, 6)], Some((10, 12)))]
+    #[case(vec![(1, 4), (2, 6)], None)]
+    #[case(vec![(1, 0)], None)]
+    #[case(vec![], Some((0, 1)))]
+    fn chinese_remainder(
+        #[case] congruences: Vec<(i128, i128)>,
+        #[case] expected: Option<(i128, i128)>,
+    ) {
+        assert_eq!(expected, crt(congruences));
+    }
+
+    #[test]
+    fn chinese_remainder_near_the_limit() {
+        let moduli = [(1i128 << 63) - 25, (1i128 << 64) - 59];
+        for residues in [
+            [i128::MAX, i128::MIN],
+            [moduli[0] - 1, moduli[1] - 1],
+            [-1, 5],
+        ] {
+            let congruences = residues.into_iter().zip(moduli);
+            let (x, combined) = crt(congruences.clone()).unwrap();
+            assert_eq!(moduli[0] * moduli[1], combined);
+            assert!((0..combined).contains(&x));
+            for (residue, modulus) in congruences {
+                assert_eq!(residue.rem_euclid(modulus), x % modulus);
+            }
+        }
+        assert_eq!(
+            None,
+            crt([(1, (1i128 << 64) - 59), (2, (1i128 << 64) - 83)])
+        );
+    }
+
+    #[test]
+    fn bus_schedule() {
+        // bus ids 7,13,x,x,59,x,31,19 each departing `offset` minutes after the timestamp
+        let buses = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)];
+        let (timestamp, _) = crt(buses.map(|(id, offset)| (-offset, id))).unwrap();
+        assert_eq!(1068781, timestamp);
+    }
+}
@@ -0,0 +1,421 @@
+use std::ops::Range;
+
+use super::matrix::{self, Matrix};
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Matrix(matrix::Error),
+    /// No pixel is lit at all.
+    Empty,
+    /// The letters are neither 6 nor 10 pixels tall.
+    UnsupportedHeight(usize),
+    /// Some cells match no letter. `text` has a `?` in their place and `columns` lists the
+    /// first column of each of them.
+    UnknownGlyphs {
+        text: String,
+        columns: Vec<usize>,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Matrix(e) => write!(f, "{}", e),
+            Error::Empty => write!(f, "no letters to recognise"),
+            Error::UnsupportedHeight(height) => {
+                write!(f, "no font with letters {} pixels tall", height)
+            }
+            Error::UnknownGlyphs { text, columns } => write!(
+                f,
+                "unknown glyphs starting at columns {:?} in '{}'",
+                columns, text
+            ),
+        }
+    }
+}
+
+impl From<matrix::Error> for Error {
+    fn from(value: matrix::Error) -> Self {
+        Error::Matrix(value)
+    }
+}
+
+/// Letters 6 pixels tall, drawn left-aligned in cells 5 columns wide. Most are 4 columns wide,
+/// `I` starts with a blank column and `Y` fills its whole cell.
+const SMALL_FONT: [(char, &[&str]); 18] = [
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters 6 pixels wide and 10 tall, drawn in cells 8 columns wide.
+const LARGE_FONT: [(char, &[&str]); 15] = [
+    (
+        'A',
+        &[
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        &[
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        &[
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        &[
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        &[
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        &[
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        &[
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        &[
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+/// Reads the letters from lit pixels, laid out like the puzzles draw them: in cells of 5
+/// columns for letters 6 pixels tall and 8 columns for letters 10 pixels tall, the first cell
+/// starting at column 0. Blank rows around the letters and blank cells are skipped, so a blank
+/// margin on the left has to be a multiple of the cell width.
+pub fn recognise(pixels: &Matrix<bool>) -> Result<String, Error> {
+    let lit_rows = lit_range((0..pixels.row_count()).map(|r| pixels[r].iter().any(|p| *p)))
+        .ok_or(Error::Empty)?;
+    let is_column_lit = |c: usize| lit_rows.clone().any(|r| pixels[(r, c)]);
+    let (font, cell_width): (&[(char, &[&str])], usize) = match lit_rows.len() {
+        6 => (&SMALL_FONT, 5),
+        10 => (&LARGE_FONT, 8),
+        height => return Err(Error::UnsupportedHeight(height)),
+    };
+    let lit_columns = lit_range((0..pixels.col_count()).map(is_column_lit)).ok_or(Error::Empty)?;
+    let first_cell = lit_columns.start / cell_width * cell_width;
+    let mut text = String::new();
+    let mut unknown = Vec::new();
+    for cell_start in (first_cell..lit_columns.end).step_by(cell_width) {
+        let cell = lit_rows
+            .clone()
+            .map(|r| {
+                (cell_start..cell_start + cell_width)
+                    .map(|c| match pixels.get(r, c) {
+                        Some(true) => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        if cell.iter().all(|row| !row.contains('#')) {
+            continue;
+        }
+        let matches = |rows: &[&str]| {
+            rows.iter().zip(&cell).all(|(glyph_row, cell_row)| {
+                cell_row.starts_with(glyph_row) && !cell_row[glyph_row.len()..].contains('#')
+            })
+        };
+        match font.iter().find(|(_, rows)| matches(rows)) {
+            Some((letter, _)) => text.push(*letter),
+            None => {
+                text.push('?');
+                unknown.push(cell_start);
+            }
+        }
+    }
+    match unknown.is_empty() {
+        true => Ok(text),
+        false => Err(Error::UnknownGlyphs {
+            text,
+            columns: unknown,
+        }),
+    }
+}
+
+/// Like [`recognise`] for text where `#` marks lit pixels.
+pub fn recognise_text(text: &str) -> Result<String, Error> {
+    recognise(&Matrix::from_text(text, |c| c == '#')?)
+}
+
+/// Indices from the first to the last lit one.
+fn lit_range(is_lit: impl Iterator<Item = bool>) -> Option<Range<usize>> {
+    let lit = is_lit
+        .enumerate()
+        .filter(|(_, lit)| *lit)
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    Some(*lit.first()?..*lit.last()? + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{matrix, recognise, recognise_text, Error, Matrix, LARGE_FONT, SMALL_FONT};
+
+    #[rstest]
+    #[case(
+        // 50 columns wide like the screen of 2016 day 8
+        "\
+.##..###...##..####.####..##..#..#..###...##.#..#.
+#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..
+#..#.###..#....###..###..#....####...#.....#.##...
+####.#..#.#....#....#....#.##.#..#...#.....#.#.#..
+#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..
+#..#.###...##..####.#.....###.#..#..###..##..#..#.",
+        "ABCEFGHIJK"
+    )]
+    #[case(
+        // 40 columns wide like the screen of 2022 day 10
+        "\
+#.....##..###..###...###.#..#.#...#####.
+#....#..#.#..#.#..#.#....#..#.#...#...#.
+#....#..#.#..#.#..#.#....#..#..#.#...#..
+#....#..#.###..###...##..#..#...#...#...
+#....#..#.#....#.#.....#.#..#...#..#....
+####..##..#....#..#.###...##....#..####.",
+        "LOPRSUYZ"
+    )]
+    #[case(
+        // the I starts with a blank column
+        "\
+.###..##..
+..#..#..#.
+..#..#..#.
+..#..####.
+..#..#..#.
+.###.#..#.",
+        "IA"
+    )]
+    #[case(
+        // cropped to the lit points like the sky of 2018 day 10
+        "\
+..##....#####....####...######..######...####...#....#.....###
+.#..#...#....#..#....#..#.......#.......#....#..#....#......#.
+#....#..#....#..#.......#.......#.......#.......#....#......#.
+#....#..#....#..#.......#.......#.......#.......#....#......#.
+#....#..#####...#.......#####...#####...#.......######......#.
+######..#....#..#.......#.......#.......#..###..#....#......#.
+#....#..#....#..#.......#.......#.......#....#..#....#......#.
+#....#..#....#..#.......#.......#.......#....#..#....#..#...#.
+#....#..#....#..#....#..#.......#.......#...##..#....#..#...#.
+#....#..#####....####...######..#........###.#..#....#...###..",
+        "ABCEFGHJ"
+    )]
+    #[case(
+        "\
+#....#..#.......#....#..#####...#####...#....#..######
+#...#...#.......##...#..#....#..#....#..#....#.......#
+#..#....#.......##...#..#....#..#....#...#..#........#
+#.#.....#.......#.#..#..#....#..#....#...#..#.......#.
+##......#.......#.#..#..#####...#####.....##.......#..
+##......#.......#..#.#..#.......#..#......##......#...
+#.#.....#.......#..#.#..#.......#...#....#..#....#....
+#..#....#.......#...##..#.......#...#....#..#...#.....
+#...#...#.......#...##..#.......#....#..#....#..#.....
+#....#..######..#....#..#.......#....#..#....#..######",
+        "KLNPRXZ"
+    )]
+    #[case(
+        "\
+#....#..#....#..#....#
+#....#..#....#..#....#
+#....#..#....#..#....#
+#....#..#....#..#....#
+######..######..######
+#....#..#....#..#....#
+#....#..#....#..#....#
+#....#..#....#..#....#
+#....#..#....#..#....#
+#....#..#....#..#....#",
+        "HHH"
+    )]
+    fn puzzle_screens(#[case] screen: &str, #[case] expected: &str) {
+        assert_eq!(Ok(expected.to_string()), recognise_text(screen));
+    }
+
+    #[test]
+    fn crt_screen() {
+        // letters 5 pixels apart, like the screens of 2016 day 8 and 2022 day 10
+        let screen = "\
+###..#....###..#..#.####.###..
+#..#.#....#..#.#..#.#....#..#.
+#..#.#....#..#.####.###..###..
+###..#....###..#..#.#....#..#.
+#.#..#....#....#..#.#....#..#.
+#..#.####.#....#..#.####.###..";
+        assert_eq!(Ok("RLPHEB".to_string()), recognise_text(screen));
+    }
+
+    #[test]
+    fn wide_letter_touching_the_next() {
+        // Y fills its whole cell, leaving no blank column before the J
+        let screen = "\
+#...#..##.
+#...#...#.
+.#.#....#.
+..#.....#.
+..#..#..#.
+..#...##..";
+        assert_eq!(Ok("YJ".to_string()), recognise_text(screen));
+    }
+
+    #[test]
+    fn from_matrix() {
+        // a blank cell to the left and blank rows above and below
+        let mut pixels = Matrix::new(8, 12);
+        for (row, line) in SMALL_FONT[7].1.iter().enumerate() {
+            for (column, c) in line.chars().enumerate() {
+                pixels[(row + 1, column + 5)] = c == '#';
+            }
+        }
+        assert_eq!(Ok("I".to_string()), recognise(&pixels));
+    }
+
+    #[test]
+    fn unknown_glyphs() {
+        let text = "\
+#..#..#...####
+#..#.#.#..#...
+####.#.#..###.
+#..#.#.#..#...
+#..#..#...#...
+#..#..#...#...";
+        assert_eq!(
+            Err(Error::UnknownGlyphs {
+                text: "H?F".to_string(),
+                columns: vec![5],
+            }),
+            recognise_text(text)
+        );
+    }
+
+    #[rstest]
+    #[case("....\n....", Error::Empty)]
+    #[case("#.#\n###\n#.#", Error::UnsupportedHeight(3))]
+    #[case(
+        "##\n#",
+        Error::Matrix(matrix::Error::RaggedRow {
+            row: 1,
+            expected: 2,
+            actual: 1
+        })
+    )]
+    fn errors(#[case] text: &str, #[case] expected: Error) {
+        assert_eq!(Err(expected), recognise_text(text));
+    }
+
+    #[test]
+    fn fonts_fit_their_cells_and_are_distinct() {
+        for (font, height, cell_width) in [(&SMALL_FONT[..], 6, 5), (&LARGE_FONT[..], 10, 8)] {
+            for (letter, rows) in font {
+                assert_eq!(height, rows.len(), "{}", letter);
+                let width = rows[0].len();
+                assert!(rows.iter().all(|r| r.len() == width), "{}", letter);
+                assert!(width < cell_width || *letter == 'Y', "{}", letter);
+                for column in [0, width - 1] {
+                    assert!(
+                        rows.iter().any(|r| r.as_bytes()[column] == b'#') || *letter == 'I',
+                        "{}",
+                        letter
+                    );
+                }
+                assert_eq!(1, font.iter().filter(|(_, r)| r == rows).count());
+            }
+        }
+    }
+}
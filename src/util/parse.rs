@@ -0,0 +1,428 @@
+use std::str::FromStr;
+
+/// Location in the input, both counted from 1. Columns count characters, not bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    /// Something that had to come next is missing, e.g. a separator.
+    Expected(String),
+    /// The text does not parse as the requested type.
+    Invalid(String),
+    /// Text left over after the whole line was expected to be parsed.
+    Trailing(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Error {
+    pub location: Location,
+    pub kind: ErrorKind,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: ",
+            self.location.line, self.location.column
+        )?;
+        match &self.kind {
+            ErrorKind::Expected(expected) => write!(f, "expected {}", expected),
+            ErrorKind::Invalid(text) => write!(f, "failed to parse '{}'", text),
+            ErrorKind::Trailing(text) => write!(f, "unexpected trailing '{}'", text),
+        }
+    }
+}
+
+/// Reads a single line from left to right. Every step either consumes what it parsed or fails
+/// with the location it failed at, so steps can be chained with `?`.
+#[derive(Clone, Debug)]
+pub struct Cursor<'a> {
+    line: &'a str,
+    line_number: usize,
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Self::at_line(line, 1)
+    }
+
+    /// Cursor whose errors report the given line number.
+    pub fn at_line(line: &'a str, line_number: usize) -> Self {
+        Self {
+            line,
+            line_number,
+            offset: 0,
+        }
+    }
+
+    pub fn location(&self) -> Location {
+        self.location_at(self.offset)
+    }
+
+    fn location_at(&self, offset: usize) -> Location {
+        Location {
+            line: self.line_number,
+            column: self.line[..offset].chars().count() + 1,
+        }
+    }
+
+    fn error_at(&self, offset: usize, kind: ErrorKind) -> Error {
+        Error {
+            location: self.location_at(offset),
+            kind,
+        }
+    }
+
+    /// Everything that is not consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.offset..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.offset == self.line.len()
+    }
+
+    /// Fails unless the whole line has been consumed.
+    pub fn end(&self) -> Result<(), Error> {
+        match self.is_at_end() {
+            true => Ok(()),
+            false => Err(self.error_at(self.offset, ErrorKind::Trailing(self.rest().to_string()))),
+        }
+    }
+
+    /// Consumes `expected`, which has to come next.
+    pub fn literal(&mut self, expected: &str) -> Result<(), Error> {
+        match self.rest().starts_with(expected) {
+            true => {
+                self.offset += expected.len();
+                Ok(())
+            }
+            false => {
+                Err(self.error_at(self.offset, ErrorKind::Expected(format!("'{}'", expected))))
+            }
+        }
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.offset += length;
+        &rest[..length]
+    }
+
+    /// Text up to the next whitespace, which is left in place.
+    pub fn word(&mut self) -> &'a str {
+        self.take_while(|c| !c.is_whitespace())
+    }
+
+    /// Text up to `delimiter`, consuming the delimiter as well.
+    pub fn until(&mut self, delimiter: &str) -> Result<&'a str, Error> {
+        let rest = self.rest();
+        match rest.find(delimiter) {
+            Some(length) => {
+                self.offset += length + delimiter.len();
+                Ok(&rest[..length])
+            }
+            None => Err(self.error_at(
+                self.line.len(),
+                ErrorKind::Expected(format!("'{}'", delimiter)),
+            )),
+        }
+    }
+
+    fn parse_at<T: FromStr>(&self, text: &str, offset: usize) -> Result<T, Error> {
+        text.parse()
+            .map_err(|_| self.error_at(offset, ErrorKind::Invalid(text.to_string())))
+    }
+
+    pub fn parse_word<T: FromStr>(&mut self) -> Result<T, Error> {
+        let start = self.offset;
+        let word = self.word();
+        self.parse_at(word, start)
+    }
+
+    pub fn parse_until<T: FromStr>(&mut self, delimiter: &str) -> Result<T, Error> {
+        let start = self.offset;
+        let text = self.until(delimiter)?;
+        self.parse_at(text, start)
+    }
+
+    pub fn parse_rest<T: FromStr>(&mut self) -> Result<T, Error> {
+        let start = self.offset;
+        let rest = self.rest();
+        self.offset = self.line.len();
+        self.parse_at(rest, start)
+    }
+
+    /// Integer with an optional leading sign that has to come next.
+    pub fn int<T: FromStr>(&mut self) -> Result<T, Error> {
+        let start = self.offset;
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return Err(self.error_at(start, ErrorKind::Expected("an integer".to_string())));
+        }
+        self.offset += sign + digits;
+        self.parse_at(&rest[..sign + digits], start)
+    }
+
+    /// Skips to the next integer and parses it, `None` if there is none left. A `-` directly
+    /// after a letter or digit is taken as a separator, not as a sign, so `2-4` is `2` and `4`.
+    pub fn next_int<T: FromStr>(&mut self) -> Option<Result<T, Error>> {
+        let mut previous = self.line[..self.offset].chars().next_back();
+        let mut chars = self.rest().char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            let is_sign = c == '-'
+                && !previous.is_some_and(char::is_alphanumeric)
+                && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit());
+            if c.is_ascii_digit() || is_sign {
+                self.offset += index;
+                return Some(self.int());
+            }
+            previous = Some(c);
+        }
+        self.offset = self.line.len();
+        None
+    }
+
+    /// Values separated by `separator` up to the end of the line. An empty rest holds no values.
+    pub fn separated_by<T: FromStr>(&mut self, separator: &str) -> Result<Vec<T>, Error> {
+        let mut values = Vec::new();
+        if self.is_at_end() {
+            return Ok(values);
+        }
+        while self.rest().contains(separator) {
+            values.push(self.parse_until(separator)?);
+        }
+        values.push(self.parse_rest()?);
+        Ok(values)
+    }
+
+    /// Key and value separated by the first `separator`, like `name: value`.
+    pub fn key_value<K: FromStr, V: FromStr>(&mut self, separator: &str) -> Result<(K, V), Error> {
+        Ok((self.parse_until(separator)?, self.parse_rest()?))
+    }
+}
+
+/// All integers in `text`, see [`Cursor::next_int`] for how signs are told apart from dashes.
+pub fn ints<T: FromStr>(text: &str) -> Result<Vec<T>, Error> {
+    let mut values = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let mut cursor = Cursor::at_line(line, index + 1);
+        while let Some(value) = cursor.next_int() {
+            values.push(value?);
+        }
+    }
+    Ok(values)
+}
+
+pub fn separated_by<T: FromStr>(line: &str, separator: &str) -> Result<Vec<T>, Error> {
+    Cursor::new(line).separated_by(separator)
+}
+
+pub fn key_value<K: FromStr, V: FromStr>(line: &str, separator: &str) -> Result<(K, V), Error> {
+    Cursor::new(line).key_value(separator)
+}
+
+/// Parses every line of `text` with `parse`, which has to consume the whole line.
+pub fn lines<'a, T>(
+    text: &'a str,
+    parse: impl FnMut(&mut Cursor<'a>) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    lines_from(text, 1, parse)
+}
+
+fn lines_from<'a, T>(
+    text: &'a str,
+    first_line: usize,
+    mut parse: impl FnMut(&mut Cursor<'a>) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let mut cursor = Cursor::at_line(line, first_line + index);
+            let value = parse(&mut cursor)?;
+            cursor.end()?;
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Lines of the input between blank lines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Block<'a> {
+    pub text: &'a str,
+    /// Line number of the first line within the whole input.
+    pub first_line: usize,
+}
+
+impl<'a> Block<'a> {
+    /// Like [`lines`], with locations relative to the whole input.
+    pub fn lines<T>(
+        &self,
+        parse: impl FnMut(&mut Cursor<'a>) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        lines_from(self.text, self.first_line, parse)
+    }
+}
+
+/// Splits the input on blank lines, skipping any number of them between blocks.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    // byte range and first line of the block being collected
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some((start, end, first_line)) = current.take() {
+                blocks.push(Block {
+                    text: &input[start..end],
+                    first_line,
+                });
+            }
+        } else {
+            let (start, first_line) = current.map_or((offset, index + 1), |(s, _, l)| (s, l));
+            current = Some((start, offset + content.len(), first_line));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end, first_line)) = current {
+        blocks.push(Block {
+            text: &input[start..end],
+            first_line,
+        });
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{blocks, ints, key_value, lines, separated_by, Cursor, Error, ErrorKind, Location};
+
+    fn error(line: usize, column: usize, kind: ErrorKind) -> Error {
+        Error {
+            location: Location { line, column },
+            kind,
+        }
+    }
+
+    #[rstest]
+    #[case("2-4,6-8", vec![2, 4, 6, 8])]
+    #[case("x=-3, y=+12", vec![-3, 12])]
+    #[case("p=0,-1 v=-2,30", vec![0, -1, -2, 30])]
+    #[case("Game 1:\n  -5 --6 a-7", vec![1, -5, -6, 7])]
+    #[case("no numbers - here", vec![])]
+    fn extract_ints(#[case] text: &str, #[case] expected: Vec<i32>) {
+        assert_eq!(Ok(expected), ints(text));
+    }
+
+    #[test]
+    fn ints_report_overflow_location() {
+        assert_eq!(
+            Err(error(2, 4, ErrorKind::Invalid("300".to_string()))),
+            ints::<u8>("1 2\nab 300")
+        );
+        assert_eq!(
+            Err(error(1, 3, ErrorKind::Invalid("-1".to_string()))),
+            ints::<u8>("a=-1")
+        );
+    }
+
+    #[rstest]
+    #[case("3   4", "   ", Ok(vec![3, 4]))]
+    #[case("7 6 4 2 1", " ", Ok(vec![7, 6, 4, 2, 1]))]
+    #[case("", ",", Ok(vec![]))]
+    #[case("1,,3", ",", Err(error(1, 3, ErrorKind::Invalid("".to_string()))))]
+    #[case("1,2,x", ",", Err(error(1, 5, ErrorKind::Invalid("x".to_string()))))]
+    fn values_separated_by(
+        #[case] line: &str,
+        #[case] separator: &str,
+        #[case] expected: Result<Vec<u32>, Error>,
+    ) {
+        assert_eq!(expected, separated_by(line, separator));
+    }
+
+    #[rstest]
+    #[case("190: 10", Ok((190, 10)))]
+    #[case("190 10", Err(error(1, 7, ErrorKind::Expected("': '".to_string()))))]
+    #[case("19x: 10", Err(error(1, 1, ErrorKind::Invalid("19x".to_string()))))]
+    fn parse_key_value(#[case] line: &str, #[case] expected: Result<(u64, u64), Error>) {
+        assert_eq!(expected, key_value(line, ": "));
+    }
+
+    #[test]
+    fn combine_steps() {
+        let parsed = lines("move 3 from 1 to 2\nmove 12 from 3 to 1", |line| {
+            line.literal("move ")?;
+            let count: usize = line.int()?;
+            line.literal(" from ")?;
+            let from: usize = line.int()?;
+            line.literal(" to ")?;
+            Ok((count, from, line.int::<usize>()?))
+        });
+        assert_eq!(Ok(vec![(3, 1, 2), (12, 3, 1)]), parsed);
+
+        let mut cursor = Cursor::new("$ cd  dir.a");
+        assert_eq!(Ok(()), cursor.literal("$ cd"));
+        cursor.skip_whitespace();
+        assert_eq!("dir.a", cursor.word());
+        assert!(cursor.is_at_end());
+    }
+
+    #[test]
+    fn lines_require_whole_line() {
+        assert_eq!(
+            Err(error(2, 2, ErrorKind::Trailing(" 5".to_string()))),
+            lines("1\n4 5", |line| line.int::<u8>())
+        );
+        assert_eq!(
+            Err(error(1, 1, ErrorKind::Expected("an integer".to_string()))),
+            lines("-", |line| line.int::<i8>())
+        );
+    }
+
+    #[test]
+    fn split_blocks() {
+        let input = "1\n2\n\n\n3 4\r\n\r\n5\n";
+        let blocks = blocks(input);
+        assert_eq!(
+            vec![("1\n2", 1), ("3 4", 5), ("5", 7)],
+            blocks
+                .iter()
+                .map(|b| (b.text, b.first_line))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Err(error(5, 2, ErrorKind::Trailing(" 4".to_string()))),
+            blocks[1].lines(|line| line.int::<u8>())
+        );
+        assert_eq!(
+            Ok(vec![vec![3, 4]]),
+            blocks[1].lines(|l| l.separated_by::<u8>(" "))
+        );
+    }
+
+    #[test]
+    fn display_error() {
+        assert_eq!(
+            "line 3, column 7: expected ','",
+            error(3, 7, ErrorKind::Expected("','".to_string())).to_string()
+        );
+    }
+}
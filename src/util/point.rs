@@ -0,0 +1,213 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::position::Position;
+
+/// Location on a signed plane. Like [`Position`], `y` grows downwards.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Displacement between two [`Point`]s.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vector<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Vector<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+fn abs_diff<T: Copy + Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    a.max(b) - a.min(b)
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point<T> {
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: Copy + TryInto<usize>> Point<T> {
+    /// Converts into a [`Position`] if both coordinates are non-negative and fit into `usize`.
+    pub fn to_position(self) -> Option<Position> {
+        Some(Position {
+            x: self.x.try_into().ok()?,
+            y: self.y.try_into().ok()?,
+        })
+    }
+}
+
+impl<T: TryFrom<usize>> TryFrom<Position> for Point<T> {
+    type Error = T::Error;
+    fn try_from(value: Position) -> Result<Self, Self::Error> {
+        Ok(Self {
+            x: T::try_from(value.x)?,
+            y: T::try_from(value.y)?,
+        })
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vector<T>> for Point<T> {
+    type Output = Self;
+    fn add(self, rhs: Vector<T>) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vector<T>> for Point<T> {
+    type Output = Self;
+    fn sub(self, rhs: Vector<T>) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Vector<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vector<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vector<T>> for Point<T> {
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vector<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vector<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Point;
+    use super::Position;
+    use super::Vector;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3i64, -2);
+        let b = Point::new(-1i64, 5);
+        let v = b - a;
+        assert_eq!(Vector::new(-4, 7), v);
+        assert_eq!(b, a + v);
+        assert_eq!(a, b - v);
+        assert_eq!(Point::new(-5, 12), b + v);
+        assert_eq!(Vector::new(-12, 21), v * 3);
+        assert_eq!(Vector::new(4, -7), -v);
+        assert_eq!(Vector::new(0, 0), v + -v);
+        let mut c = a;
+        c += v;
+        c -= Vector::new(1, 1);
+        assert_eq!(Point::new(-2, 4), c);
+    }
+
+    #[rstest]
+    #[case(Point::new(3i32, 9), Some(Position { x: 3, y: 9 }))]
+    #[case(Point::new(-1i32, 9), None)]
+    #[case(Point::new(0i32, -9), None)]
+    #[case(Point::new(0i32, 0), Some(Position { x: 0, y: 0 }))]
+    fn to_position(#[case] point: Point<i32>, #[case] expected: Option<Position>) {
+        assert_eq!(expected, point.to_position());
+    }
+
+    #[rstest]
+    #[case(Position { x: 7, y: 200 }, Some(Point::new(7i16, 200)))]
+    #[case(Position { x: 7, y: 40000 }, None)]
+    fn from_position(#[case] position: Position, #[case] expected: Option<Point<i16>>) {
+        assert_eq!(expected, Point::<i16>::try_from(position).ok());
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), Point::new(0, 0), 0, 0)]
+    #[case(Point::new(-3, 4), Point::new(2, -1), 10, 5)]
+    #[case(Point::new(-7, -2), Point::new(-1, -3), 7, 6)]
+    fn distances(
+        #[case] a: Point<i64>,
+        #[case] b: Point<i64>,
+        #[case] manhattan: i64,
+        #[case] chebyshev: i64,
+    ) {
+        assert_eq!(manhattan, a.manhattan_distance(&b));
+        assert_eq!(manhattan, b.manhattan_distance(&a));
+        assert_eq!(chebyshev, a.chebyshev_distance(&b));
+        assert_eq!(chebyshev, b.chebyshev_distance(&a));
+    }
+}
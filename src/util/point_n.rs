@@ -0,0 +1,252 @@
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// Point with `N` signed coordinates, e.g. for 3D or 4D cellular automata.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PointN<const N: usize> {
+    pub coordinates: [i64; N],
+}
+
+pub type Point3 = PointN<3>;
+pub type Point4 = PointN<4>;
+
+impl<const N: usize> Default for PointN<N> {
+    fn default() -> Self {
+        Self::origin()
+    }
+}
+
+impl<const N: usize> PointN<N> {
+    pub fn new(coordinates: [i64; N]) -> Self {
+        Self { coordinates }
+    }
+
+    pub fn origin() -> Self {
+        Self {
+            coordinates: [0; N],
+        }
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        self.coordinates
+            .iter()
+            .zip(other.coordinates.iter())
+            .map(|(a, b)| a.abs_diff(*b))
+            .sum()
+    }
+
+    /// Iterates over the `2 * N` points differing by one in exactly one coordinate.
+    pub fn orthogonal_neighbours(&self) -> impl Iterator<Item = Self> + '_ {
+        (0..N).flat_map(move |axis| {
+            [-1, 1].into_iter().map(move |delta| {
+                let mut neighbour = *self;
+                neighbour.coordinates[axis] += delta;
+                neighbour
+            })
+        })
+    }
+
+    /// Iterates over the `3^N - 1` points differing by at most one in every coordinate.
+    pub fn neighbours(&self) -> impl Iterator<Item = Self> + '_ {
+        let count = 3usize.pow(N as u32);
+        (0..count).filter(move |i| *i != count / 2).map(move |i| {
+            // every base 3 digit of `i` selects an offset of -1, 0 or 1 for one axis
+            let mut neighbour = *self;
+            let mut digits = i;
+            for coordinate in neighbour.coordinates.iter_mut() {
+                *coordinate += (digits % 3) as i64 - 1;
+                digits /= 3;
+            }
+            neighbour
+        })
+    }
+}
+
+impl<const N: usize> Index<usize> for PointN<N> {
+    type Output = i64;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.coordinates[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for PointN<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.coordinates[index]
+    }
+}
+
+impl<const N: usize> Add for PointN<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        for (a, b) in result.coordinates.iter_mut().zip(rhs.coordinates) {
+            *a += b;
+        }
+        result
+    }
+}
+
+impl<const N: usize> Sub for PointN<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const N: usize> Mul<i64> for PointN<N> {
+    type Output = Self;
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self {
+            coordinates: self.coordinates.map(|c| c * rhs),
+        }
+    }
+}
+
+impl<const N: usize> Neg for PointN<N> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            coordinates: self.coordinates.map(|c| -c),
+        }
+    }
+}
+
+/// One of the 24 proper rotations of 3D space that map axes onto axes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rotation {
+    axes: [usize; 3],
+    signs: [i64; 3],
+}
+
+impl Rotation {
+    const PERMUTATIONS: [([usize; 3], i64); 6] = [
+        ([0, 1, 2], 1),
+        ([1, 2, 0], 1),
+        ([2, 0, 1], 1),
+        ([0, 2, 1], -1),
+        ([2, 1, 0], -1),
+        ([1, 0, 2], -1),
+    ];
+
+    pub fn identity() -> Self {
+        Self {
+            axes: [0, 1, 2],
+            signs: [1, 1, 1],
+        }
+    }
+
+    /// All 24 rotations, starting with the identity.
+    pub fn all() -> Vec<Self> {
+        let mut rotations = Vec::with_capacity(24);
+        for (axes, parity) in Self::PERMUTATIONS {
+            for flips in 0..8 {
+                let signs = [0, 1, 2].map(|bit| if flips >> bit & 1 == 1 { -1 } else { 1 });
+                // reflections have a determinant of -1 and are skipped
+                if parity * signs.iter().product::<i64>() == 1 {
+                    rotations.push(Self { axes, signs });
+                }
+            }
+        }
+        rotations
+    }
+
+    pub fn apply(&self, point: &Point3) -> Point3 {
+        Point3::new([0, 1, 2].map(|i| point[self.axes[i]] * self.signs[i]))
+    }
+}
+
+impl Point3 {
+    /// The point as seen under each of the 24 rotations, in the order of [`Rotation::all`].
+    pub fn rotations(&self) -> Vec<Self> {
+        Rotation::all()
+            .iter()
+            .map(|rotation| rotation.apply(self))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use std::collections::HashSet;
+
+    use super::{Point3, Point4, PointN, Rotation};
+
+    #[test]
+    fn arithmetic() {
+        let a = Point3::new([1, -2, 3]);
+        let b = Point3::new([4, 0, -1]);
+        assert_eq!(Point3::new([5, -2, 2]), a + b);
+        assert_eq!(Point3::new([-3, -2, 4]), a - b);
+        assert_eq!(Point3::new([-2, 4, -6]), a * -2);
+        assert_eq!(Point3::origin(), a + -a);
+        assert_eq!(-2, a[1]);
+    }
+
+    #[rstest]
+    #[case(PointN::new([0, 0]), PointN::new([3, -4]), 7)]
+    #[case(PointN::new([-1, 5]), PointN::new([-1, 5]), 0)]
+    #[case(PointN::new([i64::MIN, 0]), PointN::new([i64::MAX, 0]), u64::MAX)]
+    fn manhattan_distance(#[case] a: PointN<2>, #[case] b: PointN<2>, #[case] expected: u64) {
+        assert_eq!(expected, a.manhattan_distance(&b));
+        assert_eq!(expected, b.manhattan_distance(&a));
+    }
+
+    #[test]
+    fn neighbour_counts() {
+        assert_eq!(2, PointN::<1>::origin().neighbours().count());
+        assert_eq!(8, PointN::<2>::origin().neighbours().count());
+        assert_eq!(26, Point3::origin().neighbours().count());
+        assert_eq!(80, Point4::origin().neighbours().count());
+        assert_eq!(6, Point3::origin().orthogonal_neighbours().count());
+        assert_eq!(8, Point4::origin().orthogonal_neighbours().count());
+    }
+
+    #[test]
+    fn neighbours_are_adjacent() {
+        let center = Point4::new([3, -1, 0, 7]);
+        let neighbours = center.neighbours().collect::<HashSet<_>>();
+        assert_eq!(80, neighbours.len());
+        assert!(!neighbours.contains(&center));
+        for neighbour in neighbours {
+            assert!((neighbour - center)
+                .coordinates
+                .iter()
+                .all(|c| c.abs() <= 1));
+        }
+        for neighbour in center.orthogonal_neighbours() {
+            assert_eq!(1, center.manhattan_distance(&neighbour));
+        }
+    }
+
+    #[test]
+    fn rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(24, rotations.len());
+        assert_eq!(Rotation::identity(), rotations[0]);
+        let point = Point3::new([1, 2, 3]);
+        let rotated = point.rotations();
+        assert_eq!(point, rotated[0]);
+        assert_eq!(24, rotated.iter().collect::<HashSet<_>>().len());
+        assert!(!rotated.contains(&Point3::new([-1, -2, -3])));
+        for r in rotated {
+            assert_eq!(6, r.coordinates.iter().map(|c| c.abs()).sum::<i64>());
+        }
+    }
+
+    #[test]
+    fn rotations_preserve_handedness() {
+        // rotating the x and y unit vectors must keep z as their cross product
+        let x = Point3::new([1, 0, 0]);
+        let y = Point3::new([0, 1, 0]);
+        let z = Point3::new([0, 0, 1]);
+        for rotation in Rotation::all() {
+            let (a, b) = (rotation.apply(&x), rotation.apply(&y));
+            let cross = Point3::new([
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]);
+            assert_eq!(rotation.apply(&z), cross);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use super::direction::Direction;
+use super::point::Vector;
 use super::size::Size;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -10,14 +12,35 @@ impl Position {
     pub fn is_within_size(&self, size: &Size) -> bool {
         self.x < size.width && self.y < size.height
     }
+
+    pub fn checked_add(&self, vector: Vector<isize>) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(vector.x)?,
+            y: self.y.checked_add_signed(vector.y)?,
+        })
+    }
+
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        self.checked_add(direction.to_vector())
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
+    use super::Direction;
     use super::Position;
     use super::Size;
+    use super::Vector;
 
     #[rstest]
     #[case(Position { x: 3, y: 8 }, Size { width: 10, height: 10 }, true)]
@@ -28,4 +51,45 @@ mod tests {
     fn bounds_check(#[case] position: Position, #[case] size: Size, #[case] expected: bool) {
         assert_eq!(expected, position.is_within_size(&size))
     }
+
+    #[rstest]
+    #[case(Position { x: 3, y: 8 }, Vector::new(-3, 2), Some(Position { x: 0, y: 10 }))]
+    #[case(Position { x: 3, y: 8 }, Vector::new(-4, 2), None)]
+    #[case(Position { x: usize::MAX, y: 0 }, Vector::new(1, 0), None)]
+    fn add_vector(
+        #[case] position: Position,
+        #[case] vector: Vector<isize>,
+        #[case] expected: Option<Position>,
+    ) {
+        assert_eq!(expected, position.checked_add(vector))
+    }
+
+    #[rstest]
+    #[case(Position { x: 3, y: 8 }, Direction::Up, Some(Position { x: 3, y: 7 }))]
+    #[case(Position { x: 3, y: 8 }, Direction::DownLeft, Some(Position { x: 2, y: 9 }))]
+    #[case(Position { x: 3, y: 0 }, Direction::UpRight, None)]
+    #[case(Position { x: 0, y: 5 }, Direction::Left, None)]
+    fn step(
+        #[case] position: Position,
+        #[case] direction: Direction,
+        #[case] expected: Option<Position>,
+    ) {
+        assert_eq!(expected, position.step(direction))
+    }
+
+    #[rstest]
+    #[case(Position { x: 3, y: 8 }, Position { x: 3, y: 8 }, 0, 0)]
+    #[case(Position { x: 3, y: 8 }, Position { x: 0, y: 10 }, 5, 3)]
+    #[case(Position { x: 7, y: 1 }, Position { x: 2, y: 9 }, 13, 8)]
+    fn distances(
+        #[case] a: Position,
+        #[case] b: Position,
+        #[case] manhattan: usize,
+        #[case] chebyshev: usize,
+    ) {
+        assert_eq!(manhattan, a.manhattan_distance(&b));
+        assert_eq!(manhattan, b.manhattan_distance(&a));
+        assert_eq!(chebyshev, a.chebyshev_distance(&b));
+        assert_eq!(chebyshev, b.chebyshev_distance(&a));
+    }
 }
@@ -0,0 +1,312 @@
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::num::Numeric;
+use super::number_theory::Gcd;
+
+/// Exact fraction of two `i128` values, always stored in lowest terms with a positive
+/// denominator so that derived equality and hashing work as expected.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+    pub const ONE: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Returns `None` for a zero denominator or if the fraction in lowest terms does not fit.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = numerator.checked_gcd(denominator)? * denominator.signum();
+        Some(Self {
+            numerator: numerator.checked_div(divisor)?,
+            denominator: denominator.checked_div(divisor)?,
+        })
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    pub fn recip(&self) -> Option<Self> {
+        Self::new(self.denominator, self.numerator)
+    }
+
+    /// Returns `None` if an intermediate product does not fit into an `i128`.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let divisor = self.denominator.gcd(rhs.denominator);
+        let lhs_factor = rhs.denominator / divisor;
+        let rhs_factor = self.denominator / divisor;
+        Self::new(
+            self.numerator
+                .checked_mul(lhs_factor)?
+                .checked_add(rhs.numerator.checked_mul(rhs_factor)?)?,
+            self.denominator.checked_mul(lhs_factor)?,
+        )
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // cancel crosswise first to keep the intermediate products small
+        let first = self.numerator.gcd(rhs.denominator).max(1);
+        let second = rhs.numerator.gcd(self.denominator).max(1);
+        Self::new(
+            (self.numerator / first).checked_mul(rhs.numerator / second)?,
+            (self.denominator / second).checked_mul(rhs.denominator / first)?,
+        )
+    }
+
+    /// Returns `None` when dividing by zero as well as on overflow.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+}
+
+macro_rules! rational_from_impl {
+    ($integer_type:ty) => {
+        impl From<$integer_type> for Rational {
+            fn from(value: $integer_type) -> Self {
+                Self {
+                    numerator: i128::from(value),
+                    denominator: 1,
+                }
+            }
+        }
+    };
+}
+
+rational_from_impl!(u8);
+rational_from_impl!(u16);
+rational_from_impl!(u32);
+rational_from_impl!(u64);
+rational_from_impl!(i8);
+rational_from_impl!(i16);
+rational_from_impl!(i32);
+rational_from_impl!(i64);
+rational_from_impl!(i128);
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        Mul::mul(self, rhs.recip().expect("attempt to divide by zero"))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Numeric for Rational {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if let (Some(lhs), Some(rhs)) = (
+            self.numerator.checked_mul(other.denominator),
+            other.numerator.checked_mul(self.denominator),
+        ) {
+            return lhs.cmp(&rhs);
+        }
+        // compare integer parts, then the remainders through their reciprocals, which reverses
+        // the order; like the Euclidean algorithm this terminates and never grows the values
+        let (mut a, mut b, mut c, mut d) = (
+            self.numerator,
+            self.denominator,
+            other.numerator,
+            other.denominator,
+        );
+        let mut reversed = false;
+        loop {
+            let ordering = match a.div_euclid(b).cmp(&c.div_euclid(d)) {
+                std::cmp::Ordering::Equal => {
+                    let (lhs_remainder, rhs_remainder) = (a.rem_euclid(b), c.rem_euclid(d));
+                    if lhs_remainder == 0 || rhs_remainder == 0 {
+                        lhs_remainder.cmp(&rhs_remainder)
+                    } else {
+                        (a, b, c, d) = (b, lhs_remainder, d, rhs_remainder);
+                        reversed = !reversed;
+                        continue;
+                    }
+                }
+                ordering => ordering,
+            };
+            return if reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Rational;
+
+    fn r(numerator: i128, denominator: i128) -> Rational {
+        Rational::new(numerator, denominator).unwrap()
+    }
+
+    #[rstest]
+    #[case(2, 4, Some((1, 2)))]
+    #[case(-3, -9, Some((1, 3)))]
+    #[case(3, -9, Some((-1, 3)))]
+    #[case(0, -5, Some((0, 1)))]
+    #[case(7, 0, None)]
+    fn normalize(
+        #[case] numerator: i128,
+        #[case] denominator: i128,
+        #[case] expected: Option<(i128, i128)>,
+    ) {
+        assert_eq!(
+            expected,
+            Rational::new(numerator, denominator).map(|x| (x.numerator(), x.denominator()))
+        );
+    }
+
+    #[rstest]
+    #[case(r(1, 2), r(1, 3), r(5, 6), r(1, 6), r(1, 6), r(3, 2))]
+    #[case(r(-3, 4), r(3, 4), r(0, 1), r(-3, 2), r(-9, 16), r(-1, 1))]
+    #[case(r(5, 1), r(2, 7), r(37, 7), r(33, 7), r(10, 7), r(35, 2))]
+    fn arithmetic(
+        #[case] a: Rational,
+        #[case] b: Rational,
+        #[case] sum: Rational,
+        #[case] difference: Rational,
+        #[case] product: Rational,
+        #[case] quotient: Rational,
+    ) {
+        assert_eq!(sum, a + b);
+        assert_eq!(difference, a - b);
+        assert_eq!(product, a * b);
+        assert_eq!(quotient, a / b);
+    }
+
+    #[rstest]
+    #[case(r(i128::MAX, 1), r(1, 1), None, Some(r(i128::MAX, 1)))]
+    #[case(r(1, i128::MAX), r(1, i128::MAX - 1), None, None)]
+    #[case(r(i128::MAX, 3), r(3, i128::MAX), None, Some(r(1, 1)))]
+    #[case(r(1i128 << 64, 1), r(1i128 << 64, 1), Some(r(1i128 << 65, 1)), None)]
+    fn checked_arithmetic(
+        #[case] a: Rational,
+        #[case] b: Rational,
+        #[case] sum: Option<Rational>,
+        #[case] product: Option<Rational>,
+    ) {
+        assert_eq!(sum, a.checked_add(b));
+        assert_eq!(product, a.checked_mul(b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow_panics() {
+        let _ = r(i128::MAX, 1) + r(1, 1);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(r(1, 3) < r(1, 2));
+        assert!(r(-1, 2) < r(-1, 3));
+        assert_eq!(std::cmp::Ordering::Equal, r(2, 4).cmp(&r(1, 2)));
+    }
+
+    #[rstest]
+    #[case(r(i128::MAX, i128::MAX - 1), r(i128::MAX - 1, i128::MAX - 2))]
+    #[case(r(-i128::MAX, 2), r(i128::MAX, 3))]
+    #[case(r(i128::MAX - 1, i128::MAX), r(1, 1))]
+    #[case(r(-1, 1), r(-(i128::MAX - 1), i128::MAX))]
+    #[case(r(i128::MAX / 2, 3), r(i128::MAX / 2 + 1, 3))]
+    fn ordering_without_overflow(#[case] smaller: Rational, #[case] larger: Rational) {
+        assert_eq!(std::cmp::Ordering::Less, smaller.cmp(&larger));
+        assert_eq!(std::cmp::Ordering::Greater, larger.cmp(&smaller));
+        assert_eq!(std::cmp::Ordering::Equal, larger.cmp(&larger));
+    }
+
+    #[rstest]
+    #[case(r(6, 3), "2", Some(2))]
+    #[case(r(-6, 4), "-3/2", None)]
+    fn display(#[case] value: Rational, #[case] expected: &str, #[case] integer: Option<i128>) {
+        assert_eq!(expected, value.to_string());
+        assert_eq!(integer, value.to_integer());
+    }
+}
@@ -0,0 +1,321 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Everything learned while exploring a graph from a single start node.
+#[derive(Debug)]
+pub struct SearchResult<N, C> {
+    /// Cost of the path found to every reached node, the start having the default cost.
+    pub distances: HashMap<N, C>,
+    /// Node preceding every reached node except the start on the path found to it.
+    pub predecessors: HashMap<N, N>,
+    /// Reached nodes in the order they were visited.
+    pub order: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C> SearchResult<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<&C> {
+        self.distances.get(node)
+    }
+
+    /// Reconstructs the path from the start to `target`, both included.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+        Some(reconstruct_path(&self.predecessors, target.clone()))
+    }
+}
+
+fn reconstruct_path<N: Clone + Eq + Hash>(predecessors: &HashMap<N, N>, target: N) -> Vec<N> {
+    let mut path = vec![target];
+    while let Some(previous) = predecessors.get(path.last().unwrap()) {
+        path.push(previous.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Breadth first search, the distances being the number of edges on a shortest path.
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::from([start.clone()]);
+    result.distances.insert(start, 0);
+    while let Some(node) = queue.pop_front() {
+        let distance = result.distances[&node];
+        for next in neighbours(&node) {
+            if !result.distances.contains_key(&next) {
+                result.distances.insert(next.clone(), distance + 1);
+                result.predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+        result.order.push(node);
+    }
+    result
+}
+
+/// Depth first search in pre-order, the distances being the depth in the search tree.
+pub fn dfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    let mut visited = HashSet::new();
+    let mut stack = vec![(start, None, 0usize)];
+    while let Some((node, predecessor, depth)) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        result.distances.insert(node.clone(), depth);
+        if let Some(predecessor) = predecessor {
+            result.predecessors.insert(node.clone(), predecessor);
+        }
+        // pushed in reverse so the first neighbour is explored first
+        let next_nodes = neighbours(&node).into_iter().collect::<Vec<N>>();
+        for next in next_nodes.into_iter().rev() {
+            if !visited.contains(&next) {
+                stack.push((next, Some(node.clone()), depth + 1));
+            }
+        }
+        result.order.push(node);
+    }
+    result
+}
+
+struct State<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reversed so that [`BinaryHeap`] pops the lowest priority first.
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+fn best_first<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (SearchResult<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult::new();
+    let mut settled = HashSet::new();
+    let mut heap = BinaryHeap::new();
+    result.distances.insert(start.clone(), C::default());
+    heap.push(State {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    });
+    while let Some(State { cost, node, .. }) = heap.pop() {
+        if !settled.insert(node.clone()) {
+            continue;
+        }
+        result.order.push(node.clone());
+        if is_goal(&node) {
+            return (result, Some(node));
+        }
+        for (next, edge_cost) in neighbours(&node) {
+            let next_cost = cost + edge_cost;
+            if result
+                .distances
+                .get(&next)
+                .is_none_or(|known| next_cost < *known)
+            {
+                result.distances.insert(next.clone(), next_cost);
+                result.predecessors.insert(next.clone(), node.clone());
+                heap.push(State {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    (result, None)
+}
+
+/// Dijkstra's algorithm over non-negative edge costs, exploring every reachable node.
+pub fn dijkstra<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, neighbours, |_| C::default(), |_| false).0
+}
+
+/// Cheapest path from `start` to the first node satisfying `is_goal`, found with A*.
+///
+/// Settled nodes are never expanded again, so the `heuristic` must be consistent: it may not
+/// drop by more than the cost of any edge and must be zero at the goals. A merely admissible
+/// heuristic can make the returned path more expensive than the cheapest. A heuristic returning the default cost turns this into Dijkstra's
+/// algorithm with an early exit.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (result, goal) = best_first(start, neighbours, heuristic, is_goal);
+    let goal = goal?;
+    let cost = result.distances[&goal];
+    Some((reconstruct_path(&result.predecessors, goal), cost))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rstest::rstest;
+
+    use super::{astar, bfs, dfs, dijkstra};
+    use crate::util::matrix::Matrix;
+
+    const MAZE: &str = "S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn maze() -> Matrix<char> {
+        MAZE.parse().unwrap()
+    }
+
+    fn open_neighbours(maze: &Matrix<char>, index: &(usize, usize)) -> Vec<(usize, usize)> {
+        maze.neighbours(*index)
+            .filter(|(_, c)| **c != '#')
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_distances_and_path() {
+        let maze = maze();
+        let result = bfs((0, 0), |i| open_neighbours(&maze, i));
+        assert_eq!(Some(&15), result.distance(&(4, 7)));
+        assert_eq!(None, result.distance(&(0, 3)));
+        let path = result.path_to(&(4, 7)).unwrap();
+        assert_eq!(16, path.len());
+        assert_eq!(Some(&(0, 0)), path.first());
+        assert_eq!(Some(&(4, 7)), path.last());
+        for pair in path.windows(2) {
+            assert_eq!(
+                1,
+                pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1)
+            );
+        }
+        assert_eq!(None, result.path_to(&(1, 1)));
+        assert_eq!(Some(vec![(0, 0)]), result.path_to(&(0, 0)));
+    }
+
+    #[test]
+    fn bfs_order_is_by_distance() {
+        let maze = maze();
+        let result = bfs((0, 0), |i| open_neighbours(&maze, i));
+        assert_eq!(result.distances.len(), result.order.len());
+        assert!(result
+            .order
+            .windows(2)
+            .all(|pair| result.distances[&pair[0]] <= result.distances[&pair[1]]));
+    }
+
+    #[test]
+    fn dfs_preorder() {
+        // 1 -> 2 -> 4, 1 -> 3 -> 4, 4 -> 1
+        let edges = HashMap::from([(1, vec![2, 3]), (2, vec![4]), (3, vec![4]), (4, vec![1])]);
+        let result = dfs(1, |n| edges[n].clone());
+        assert_eq!(vec![1, 2, 4, 3], result.order);
+        assert_eq!(Some(&2), result.distance(&4));
+        assert_eq!(Some(vec![1, 2, 4]), result.path_to(&4));
+        assert_eq!(Some(vec![1, 3]), result.path_to(&3));
+    }
+
+    fn weighted_graph(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 9), ('f', 14)],
+            'b' => vec![('a', 7), ('c', 10), ('d', 15)],
+            'c' => vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)],
+            'd' => vec![('b', 15), ('c', 11), ('e', 6)],
+            'e' => vec![('d', 6), ('f', 9)],
+            'f' => vec![('a', 14), ('c', 2), ('e', 9)],
+            _ => vec![],
+        }
+    }
+
+    #[rstest]
+    #[case('a', 0, "a")]
+    #[case('b', 7, "ab")]
+    #[case('c', 9, "ac")]
+    #[case('d', 20, "acd")]
+    #[case('e', 20, "acfe")]
+    #[case('f', 11, "acf")]
+    fn dijkstra_distances(#[case] target: char, #[case] expected: u32, #[case] path: &str) {
+        let result = dijkstra('a', weighted_graph);
+        assert_eq!(Some(&expected), result.distance(&target));
+        assert_eq!(Some(path.chars().collect()), result.path_to(&target));
+    }
+
+    #[test]
+    fn astar_on_grid() {
+        let maze = maze();
+        let goal = (4usize, 7usize);
+        let (path, cost) = astar(
+            (0usize, 0usize),
+            |i| open_neighbours(&maze, i).into_iter().map(|n| (n, 1usize)),
+            |i| i.0.abs_diff(goal.0) + i.1.abs_diff(goal.1),
+            |i| *i == goal,
+        )
+        .unwrap();
+        assert_eq!(15, cost);
+        assert_eq!(16, path.len());
+        assert_eq!(Some(&goal), path.last());
+    }
+
+    #[test]
+    fn astar_unreachable() {
+        assert_eq!(None, astar('a', weighted_graph, |_| 0, |n| *n == 'z'));
+    }
+}
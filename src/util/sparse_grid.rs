@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+
+use super::matrix::{ORTHOGONAL_OFFSETS, SURROUNDING_OFFSETS};
+
+/// Smallest rectangle containing every occupied cell, all limits inclusive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bounds {
+    pub min_row: i64,
+    pub max_row: i64,
+    pub min_column: i64,
+    pub max_column: i64,
+}
+
+impl Bounds {
+    fn around(index: (i64, i64)) -> Self {
+        Self {
+            min_row: index.0,
+            max_row: index.0,
+            min_column: index.1,
+            max_column: index.1,
+        }
+    }
+
+    fn extend(&mut self, index: (i64, i64)) {
+        self.min_row = self.min_row.min(index.0);
+        self.max_row = self.max_row.max(index.0);
+        self.min_column = self.min_column.min(index.1);
+        self.max_column = self.max_column.max(index.1);
+    }
+
+    fn is_on_border(&self, index: (i64, i64)) -> bool {
+        index.0 == self.min_row
+            || index.0 == self.max_row
+            || index.1 == self.min_column
+            || index.1 == self.max_column
+    }
+
+    /// Number of rows, `None` if the bounds span all of `i64` and that does not fit into a `u64`.
+    pub fn row_count(&self) -> Option<u64> {
+        self.min_row.abs_diff(self.max_row).checked_add(1)
+    }
+
+    /// Number of columns, `None` like [`Bounds::row_count`] for a span of all of `i64`.
+    pub fn col_count(&self) -> Option<u64> {
+        self.min_column.abs_diff(self.max_column).checked_add(1)
+    }
+}
+
+/// Grid without fixed dimensions, indexed by signed `(row, column)` pairs like [`Matrix`] is
+/// indexed by unsigned ones. Only occupied cells are stored.
+///
+/// [`Matrix`]: super::matrix::Matrix
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Builds a grid from a block of text with the top left character at `(0, 0)`, skipping
+    /// every character for which `f` returns `None`.
+    pub fn from_text(text: &str, f: impl Fn(char) -> Option<T>) -> Self {
+        let mut grid = Self::new();
+        for (row, line) in (0i64..).zip(text.lines()) {
+            for (column, c) in (0i64..).zip(line.chars()) {
+                if let Some(value) = f(c) {
+                    grid.insert((row, column), value);
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn get(&self, index: (i64, i64)) -> Option<&T> {
+        self.cells.get(&index)
+    }
+
+    pub fn get_mut(&mut self, index: (i64, i64)) -> Option<&mut T> {
+        self.cells.get_mut(&index)
+    }
+
+    pub fn contains(&self, index: (i64, i64)) -> bool {
+        self.cells.contains_key(&index)
+    }
+
+    pub fn insert(&mut self, index: (i64, i64), value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(index),
+            None => self.bounds = Some(Bounds::around(index)),
+        }
+        self.cells.insert(index, value)
+    }
+
+    pub fn remove(&mut self, index: (i64, i64)) -> Option<T> {
+        let value = self.cells.remove(&index)?;
+        if self.bounds.is_some_and(|b| b.is_on_border(index)) {
+            self.recompute_bounds();
+        }
+        Some(value)
+    }
+
+    fn recompute_bounds(&mut self) {
+        let mut indices = self.cells.keys();
+        self.bounds = indices.next().map(|first| {
+            let mut bounds = Bounds::around(*first);
+            for index in indices {
+                bounds.extend(*index);
+            }
+            bounds
+        });
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells.iter().map(|(index, value)| (*index, value))
+    }
+
+    /// Iterates over the occupied cells among the four orthogonally adjacent ones.
+    pub fn neighbours(&self, index: (i64, i64)) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.offset_cells(index, &ORTHOGONAL_OFFSETS)
+    }
+
+    /// Iterates over the occupied cells among the eight surrounding ones.
+    pub fn neighbours_with_diagonals(
+        &self,
+        index: (i64, i64),
+    ) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.offset_cells(index, &SURROUNDING_OFFSETS)
+    }
+
+    fn offset_cells<'a>(
+        &'a self,
+        index: (i64, i64),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = ((i64, i64), &'a T)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |(row_offset, column_offset)| {
+                let row = index.0.checked_add(*row_offset as i64)?;
+                let column = index.1.checked_add(*column_offset as i64)?;
+                self.get((row, column)).map(|value| ((row, column), value))
+            })
+    }
+
+    /// Renders the bounding box one line per row, passing `None` for unoccupied cells.
+    pub fn render_with(&self, f: impl Fn(Option<&T>) -> char) -> String {
+        let mut text = String::new();
+        if let Some(bounds) = self.bounds {
+            for row in bounds.min_row..=bounds.max_row {
+                text.extend(
+                    (bounds.min_column..=bounds.max_column)
+                        .map(|column| f(self.get((row, column)))),
+                );
+                text.push('\n');
+            }
+        }
+        text
+    }
+}
+
+impl<T> FromIterator<((i64, i64), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i64, i64), T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (index, value) in iter {
+            grid.insert(index, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Bounds;
+    use super::SparseGrid;
+
+    #[test]
+    fn bounds_follow_insertions() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+        grid.insert((-3, 5), 'a');
+        grid.insert((2, -7), 'b');
+        assert_eq!(
+            Some(Bounds {
+                min_row: -3,
+                max_row: 2,
+                min_column: -7,
+                max_column: 5
+            }),
+            grid.bounds()
+        );
+        assert_eq!(Some(6), grid.bounds().unwrap().row_count());
+        assert_eq!(Some(13), grid.bounds().unwrap().col_count());
+
+        grid.insert((i64::MAX, i64::MIN), 'c');
+        grid.insert((i64::MIN, i64::MAX - 1), 'd');
+        assert_eq!(None, grid.bounds().unwrap().row_count());
+        assert_eq!(Some(u64::MAX), grid.bounds().unwrap().col_count());
+    }
+
+    #[test]
+    fn bounds_shrink_on_removal() {
+        let mut grid: SparseGrid<u8> = [((0, 0), 1), ((-4, 1), 2), ((1, 9), 3)]
+            .into_iter()
+            .collect();
+        assert_eq!(Some(2), grid.remove((-4, 1)));
+        assert_eq!(None, grid.remove((-4, 1)));
+        assert_eq!(
+            Some(Bounds {
+                min_row: 0,
+                max_row: 1,
+                min_column: 0,
+                max_column: 9
+            }),
+            grid.bounds()
+        );
+        grid.remove((0, 0));
+        grid.remove((1, 9));
+        assert!(grid.is_empty());
+        assert_eq!(None, grid.bounds());
+    }
+
+    #[rstest]
+    #[case((1, 1), vec![(1, 2)], vec![(0, 0), (0, 2), (1, 2), (2, 2)])]
+    #[case((0, -1), vec![(0, 0)], vec![(0, 0)])]
+    #[case((i64::MAX, 0), vec![], vec![])]
+    fn neighbours(
+        #[case] index: (i64, i64),
+        #[case] expected: Vec<(i64, i64)>,
+        #[case] expected_with_diagonals: Vec<(i64, i64)>,
+    ) {
+        let grid = SparseGrid::from_text("#.#\n.##\n..#", |c| (c == '#').then_some(()));
+        assert_eq!(
+            expected,
+            grid.neighbours(index).map(|(i, _)| i).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            expected_with_diagonals,
+            grid.neighbours_with_diagonals(index)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn render() {
+        let mut grid = SparseGrid::new();
+        grid.insert((-1, -1), 1u8);
+        grid.insert((1, 2), 2u8);
+        grid.insert((0, 0), 3u8);
+        assert_eq!(
+            "1...\n.3..\n...2\n",
+            grid.render_with(|cell| cell.map_or('.', |v| char::from(b'0' + v)))
+        );
+        assert_eq!("", SparseGrid::<u8>::new().render_with(|_| '#'));
+    }
+
+    #[test]
+    fn parse_text() {
+        let grid = SparseGrid::from_text("..#\n#..\n", |c| (c == '#').then_some(c));
+        assert_eq!(2, grid.len());
+        assert!(grid.contains((0, 2)));
+        assert!(grid.contains((1, 0)));
+        assert_eq!("..#\n#..\n", grid.render_with(|c| *c.unwrap_or(&'.')));
+    }
+}
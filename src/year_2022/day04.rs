@@ -1,5 +1,7 @@
 use std::ops::RangeInclusive;
 
+use crate::util::parse::{separated_by, Cursor};
+
 pub fn get_contained_pair_count(input: &str) -> Option<i32> {
     let mut sum = 0;
     for line in input.lines() {
@@ -23,26 +25,25 @@ pub fn get_overlapping_pair_count(input: &str) -> Option<i32> {
 }
 
 fn parse_ranges(line: &str) -> Option<(RangeInclusive<i32>, RangeInclusive<i32>)> {
-    let line: &str = line.trim();
-    let mut ranges: Vec<RangeInclusive<i32>> = Vec::new();
-    for range in line.split(',') {
-        let mut tmp: Vec<i32> = Vec::new();
-        for entry in range.split('-') {
-            tmp.push((*entry).parse::<i32>().ok()?)
-        }
-        ranges.push(RangeInclusive::new(tmp[0], tmp[1]));
+    let mut cursor = Cursor::new(line.trim());
+    let first = parse_range(cursor.until(",").ok()?)?;
+    Some((first, parse_range(cursor.rest())?))
+}
+
+fn parse_range(text: &str) -> Option<RangeInclusive<i32>> {
+    match separated_by::<i32>(text, "-").ok()?[..] {
+        [start, end] => Some(start..=end),
+        _ => None,
     }
-    Some((ranges[0].clone(), ranges[1].clone()))
 }
 
 fn is_range_contained(first: RangeInclusive<i32>, second: RangeInclusive<i32>) -> bool {
-    (first.contains(second.start()) && first.contains(second.end()))
-        || (second.contains(first.start()) && second.contains(first.end()))
+    (first.start() <= second.start() && second.end() <= first.end())
+        || (second.start() <= first.start() && first.end() <= second.end())
 }
 
 fn is_range_overlapping(first: RangeInclusive<i32>, second: RangeInclusive<i32>) -> bool {
-    (first.contains(second.start()) || first.contains(second.end()))
-        || (second.contains(first.start()) || second.contains(first.end()))
+    first.start() <= second.end() && second.start() <= first.end()
 }
 
 #[cfg(test)]
@@ -68,11 +69,27 @@ mod tests {
         assert_eq!(get_overlapping_pair_count(TEST_INPUT), Some(4));
     }
 
+    #[rstest]
+    #[case("2-4,6-8", Some((2..=4, 6..=8)))]
+    #[case(" 6-6,4-6 ", Some((6..=6, 4..=6)))]
+    #[case("a2b4c6d8", None)]
+    #[case("2-4 6-8", None)]
+    #[case("2-4,6-8,1-2", None)]
+    #[case("2-4-5,6-8", None)]
+    #[case("2-4,", None)]
+    fn ranges(
+        #[case] line: &str,
+        #[case] expected: Option<(RangeInclusive<i32>, RangeInclusive<i32>)>,
+    ) {
+        assert_eq!(expected, parse_ranges(line))
+    }
+
     #[rstest]
     #[case(1..=6, 3..=6, true)]
     #[case(1..=5, 3..=6, true)]
     #[case(3..=6, 1..=6, true)]
     #[case(1..=2, 3..=6, false)]
+    #[case(1..=i32::MAX, i32::MAX..=i32::MAX, true)]
     fn overlapping(
         #[case] first_inclusive_range: RangeInclusive<i32>,
         #[case] second_inclusive_range: RangeInclusive<i32>,
@@ -89,6 +106,7 @@ mod tests {
     #[case(3..=6, 1..=6, true)]
     #[case(1..=5, 3..=6, false)]
     #[case(1..=2, 3..=6, false)]
+    #[case(i32::MAX..=i32::MAX, 1..=i32::MAX, true)]
     fn contained(
         #[case] first_inclusive_range: RangeInclusive<i32>,
         #[case] second_inclusive_range: RangeInclusive<i32>,
@@ -1,28 +1,12 @@
-use std::num::ParseIntError;
+use crate::util::parse::{lines, Error};
 
-fn parse_lists(list_input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseIntError> {
-    let lines = list_input.lines();
-    let mut left_list: Vec<u32> = vec![];
-    let mut right_list: Vec<u32> = vec![];
-    for line in lines {
-        let mut split = line.split("   ");
-        left_list.push(
-            split
-                .next()
-                .expect("expected 'val1   val2'")
-                .parse::<u32>()?,
-        );
-        right_list.push(
-            split
-                .next()
-                .expect("expected 'val1   val2'")
-                .parse::<u32>()?,
-        );
-    }
-    Ok((left_list, right_list))
+fn parse_lists(list_input: &str) -> Result<(Vec<u32>, Vec<u32>), Error> {
+    Ok(lines(list_input, |line| line.key_value::<u32, u32>("   "))?
+        .into_iter()
+        .unzip())
 }
 
-pub fn get_list_difference(list_input: &str) -> Result<u32, ParseIntError> {
+pub fn get_list_difference(list_input: &str) -> Result<u32, Error> {
     let (mut left_list, mut right_list) = parse_lists(list_input)?;
     left_list.sort();
     right_list.sort();
@@ -33,7 +17,7 @@ pub fn get_list_difference(list_input: &str) -> Result<u32, ParseIntError> {
         .fold(0u32, |acc, (l, r)| acc + l.abs_diff(*r)))
 }
 
-pub fn get_list_similarity_score(list_input: &str) -> Result<u32, ParseIntError> {
+pub fn get_list_similarity_score(list_input: &str) -> Result<u32, Error> {
     let (left_list, right_list) = parse_lists(list_input)?;
 
     Ok(left_list.iter().fold(0u32, |acc, i| {
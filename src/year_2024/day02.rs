@@ -1,11 +1,7 @@
-use std::{num::ParseIntError, u32};
+use crate::util::parse::{lines, Cursor, Error};
 
-fn parse_report(report: &str) -> Result<Vec<u32>, ParseIntError> {
-    Ok(report
-        .split(" ")
-        .into_iter()
-        .map(|d| d.parse::<u32>().unwrap())
-        .collect())
+fn parse_report(report: &mut Cursor) -> Result<Vec<u32>, Error> {
+    report.separated_by(" ")
 }
 
 fn report_is_safe(items: &Vec<u32>) -> bool {
@@ -23,12 +19,12 @@ fn report_is_safe(items: &Vec<u32>) -> bool {
         })
 }
 
-fn is_safe(report: &str) -> Result<bool, ParseIntError> {
+fn is_safe(report: &mut Cursor) -> Result<bool, Error> {
     let digits: Vec<u32> = parse_report(report)?;
     Ok(report_is_safe(&digits))
 }
 
-fn is_safe_dampened(report: &str) -> Result<bool, ParseIntError> {
+fn is_safe_dampened(report: &mut Cursor) -> Result<bool, Error> {
     let digits: Vec<u32> = parse_report(report)?;
     if report_is_safe(&digits) {
         Ok(true)
@@ -44,24 +40,18 @@ fn is_safe_dampened(report: &str) -> Result<bool, ParseIntError> {
     }
 }
 
-pub fn count_safe_reports(input: &str) -> Result<usize, ParseIntError> {
-    Ok(input
-        .lines()
+pub fn count_safe_reports(input: &str) -> Result<usize, Error> {
+    Ok(lines(input, is_safe)?
         .into_iter()
-        .filter(|l| is_safe(l).unwrap())
-        .count()
-        .try_into()
-        .unwrap())
+        .filter(|safe| *safe)
+        .count())
 }
 
-pub fn count_safe_reports_dampened(input: &str) -> Result<usize, ParseIntError> {
-    Ok(input
-        .lines()
+pub fn count_safe_reports_dampened(input: &str) -> Result<usize, Error> {
+    Ok(lines(input, is_safe_dampened)?
         .into_iter()
-        .filter(|l| is_safe_dampened(l).unwrap())
-        .count()
-        .try_into()
-        .unwrap())
+        .filter(|safe| *safe)
+        .count())
 }
 
 #[cfg(test)]
@@ -84,8 +74,8 @@ mod tests {
     #[case("1 3 2 4 5", Ok(false))]
     #[case("8 6 4 4 1", Ok(false))]
     #[case("1 3 6 7 9", Ok(true))]
-    fn check_is_safe(#[case] input: &str, #[case] expected: Result<bool, ParseIntError>) {
-        assert_eq!(expected, is_safe(input))
+    fn check_is_safe(#[case] input: &str, #[case] expected: Result<bool, Error>) {
+        assert_eq!(expected, is_safe(&mut Cursor::new(input)))
     }
 
     #[test]
@@ -100,8 +90,8 @@ mod tests {
     #[case("1 3 2 4 5", Ok(true))]
     #[case("8 6 4 4 1", Ok(true))]
     #[case("1 3 6 7 9", Ok(true))]
-    fn check_is_safe_dampened(#[case] input: &str, #[case] expected: Result<bool, ParseIntError>) {
-        assert_eq!(expected, is_safe_dampened(input))
+    fn check_is_safe_dampened(#[case] input: &str, #[case] expected: Result<bool, Error>) {
+        assert_eq!(expected, is_safe_dampened(&mut Cursor::new(input)))
     }
 
     #[test]
@@ -1,3 +1,5 @@
+use crate::util::graph::DiGraph;
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Error {
     OrderingRuleParsingFailed,
@@ -154,26 +156,17 @@ pub fn sum_middle_page_numbers_of_valid_print_orders(input: &str) -> Result<u32,
 }
 
 fn fix_order(order: &Vec<u32>, rules: &Vec<OrderingRule>) -> Option<Vec<u32>> {
-    let mut order: Vec<u32> = order.clone();
-    let mut iterations: usize = 0usize;
-    while rules.iter().any(|r| !r.is_satisfied(&order)) {
-        if iterations > 1 << 10 {
-            return None;
-        }
-        let mut fixed = order.clone();
-        for rule in rules.iter().filter(|r| !r.is_satisfied(&order)) {
-            fixed.swap(
-                order.iter().position(|&i| i == rule.first).unwrap(),
-                order.iter().position(|&i| i == rule.second).unwrap(),
-            );
-            if rules.iter().all(|r| r.is_satisfied(&order)) {
-                break;
-            }
-        }
-        order = fixed;
-        iterations += 1;
+    let mut graph = DiGraph::new();
+    for page in order {
+        graph.add_node(*page);
     }
-    Some(order)
+    for rule in rules
+        .iter()
+        .filter(|r| order.contains(&r.first) && order.contains(&r.second))
+    {
+        graph.add_edge(rule.first, rule.second);
+    }
+    graph.topological_sort().ok()
 }
 
 pub fn sum_middle_page_numbers_of_fixed_invalid_print_orders(input: &str) -> Result<u32, Error> {
@@ -243,6 +236,13 @@ mod tests {
         assert_eq!(expected, fix_order(&order, &rules))
     }
 
+    #[test]
+    fn fix_order_with_contradictory_rules() {
+        let (rules, _) = parse_input("1|2\n2|3\n3|1\n\n1,2,3").unwrap();
+        assert_eq!(None, fix_order(&vec![1, 2, 3], &rules));
+        assert_eq!(Some(vec![1, 2]), fix_order(&vec![2, 1], &rules));
+    }
+
     #[test]
     fn sum_fixed_orders() {
         assert_eq!(
@@ -1,4 +1,6 @@
-use std::collections::HashSet;
+use crate::util::bitset::{BitGrid, BitSet};
+use crate::util::position::Position;
+use crate::util::size::Size;
 
 #[derive(Debug, PartialEq)]
 pub enum Error {
@@ -21,7 +23,7 @@ impl std::convert::From<std::boxed::Box<dyn std::any::Any + std::marker::Send>>
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 enum GuardOrientation {
     Up,
     Right,
@@ -66,7 +68,7 @@ impl Guard {
 struct Map {
     guard: Option<Guard>,
     size: (usize, usize),
-    obstacles: HashSet<(usize, usize)>,
+    obstacles: BitGrid,
     iterations: usize,
 }
 
@@ -85,7 +87,7 @@ impl std::str::FromStr for Map {
         } else {
             Ok(())
         }?;
-        let mut obstacles: HashSet<(usize, usize)> = HashSet::new();
+        let mut obstacles = BitGrid::new(Size { width, height });
         let mut opt_guard: Option<Guard> = None;
         for (y, line) in lines.enumerate() {
             match (&opt_guard, line.find('^')) {
@@ -128,12 +130,8 @@ impl std::str::FromStr for Map {
                 (Some(_), Some(_)) => return Err(Error::MapParsingFailed),
                 _ => (),
             }
-            let found_obstacles = line
-                .match_indices('#')
-                .map(|(x, _)| (x, y))
-                .collect::<Vec<(usize, usize)>>();
-            for found in found_obstacles {
-                obstacles.insert(found);
+            for (x, _) in line.match_indices('#') {
+                obstacles.insert(&Position { x, y });
             }
         }
         match &opt_guard {
@@ -160,7 +158,10 @@ impl Iterator for Map {
         {
             Some(next_position) => {
                 let mut guard = self.guard?;
-                if self.obstacles.contains(&next_position) {
+                if self.obstacles.contains(&Position {
+                    x: next_position.0,
+                    y: next_position.1,
+                }) {
                     guard.rotate()
                 } else {
                     guard.step()
@@ -175,31 +176,36 @@ impl Iterator for Map {
 
 impl Map {
     fn loops(&mut self) -> bool {
-        let mut visited: HashSet<(usize, usize, GuardOrientation)> = HashSet::<_>::new();
-        for x in self.into_iter() {
-            if !visited.insert(x) {
+        // one bit per cell and orientation
+        let width = self.size.0;
+        let mut visited = BitSet::new(width * self.size.1 * 4);
+        for (x, y, orientation) in self.into_iter() {
+            if !visited.insert((y * width + x) * 4 + orientation as usize) {
                 return true;
             }
         }
         false
     }
+
+    fn visited_positions(self) -> BitGrid {
+        let mut visited = BitGrid::new(self.obstacles.size().clone());
+        for (x, y, _) in self {
+            visited.insert(&Position { x, y });
+        }
+        visited
+    }
 }
 
 pub fn count_positions(input: &str) -> Result<usize, Error> {
-    let x: Map = input.parse()?;
-    Ok(x.into_iter()
-        .map(|(x, y, _)| (x, y))
-        .collect::<Vec<(usize, usize)>>()
-        .into_iter()
-        .collect::<HashSet<(usize, usize)>>()
-        .len())
+    let map: Map = input.parse()?;
+    Ok(map.visited_positions().count())
 }
 
-fn loop_check_thread_proc(map: &Map, possible_positions: &[(usize, usize)]) -> usize {
+fn loop_check_thread_proc(map: &Map, possible_positions: &[Position]) -> usize {
     let mut count = 0usize;
     for p in possible_positions {
         let mut map = map.clone();
-        if !map.obstacles.insert(*p) {
+        if !map.obstacles.insert(p) {
             panic!("obstacle already present");
         }
         if map.loops() {
@@ -211,15 +217,7 @@ fn loop_check_thread_proc(map: &Map, possible_positions: &[(usize, usize)]) -> u
 
 pub fn count_loop_positions(input: &str) -> Result<usize, Error> {
     let map: Map = input.parse()?;
-    let possible_positions: Vec<(usize, usize)> = map
-        .clone()
-        .into_iter()
-        .map(|(x, y, _)| (x, y))
-        .collect::<Vec<_>>()
-        .into_iter()
-        .collect::<HashSet<_>>()
-        .into_iter()
-        .collect();
+    let possible_positions: Vec<Position> = map.clone().visited_positions().iter().collect();
     Ok(
         match std::thread::available_parallelism()
             .ok()
@@ -229,7 +227,7 @@ pub fn count_loop_positions(input: &str) -> Result<usize, Error> {
             Some(thread_count) => {
                 let thread_count = thread_count.get();
                 let map: std::sync::Arc<Map> = std::sync::Arc::new(map);
-                let possible_positions: std::sync::Arc<Vec<(usize, usize)>> =
+                let possible_positions: std::sync::Arc<Vec<Position>> =
                     std::sync::Arc::new(possible_positions);
                 let mut position_count: usize;
                 let step_size: usize = possible_positions.len() / thread_count;
@@ -346,16 +344,16 @@ mod tests {
     }
 
     #[rstest]
-    #[case((3usize, 6usize))]
-    #[case((6usize, 7usize))]
-    #[case((7usize, 7usize))]
-    #[case((1usize, 8usize))]
-    #[case((3usize, 8usize))]
-    #[case((7usize, 9usize))]
-    fn loop_test(#[case] obstacle_position: (usize, usize)) {
+    #[case(Position { x: 3, y: 6 })]
+    #[case(Position { x: 6, y: 7 })]
+    #[case(Position { x: 7, y: 7 })]
+    #[case(Position { x: 1, y: 8 })]
+    #[case(Position { x: 3, y: 8 })]
+    #[case(Position { x: 7, y: 9 })]
+    fn loop_test(#[case] obstacle_position: Position) {
         let mut map: Map = TEST_STR.parse().unwrap();
         assert!(!map.clone().loops());
-        if !map.obstacles.insert(obstacle_position) {
+        if !map.obstacles.insert(&obstacle_position) {
             panic!("obstacle already present");
         }
         assert!(map.loops());
@@ -368,6 +366,22 @@ mod tests {
 
     #[test]
     fn parse_map() {
+        let mut obstacles = BitGrid::new(Size {
+            width: 10,
+            height: 10,
+        });
+        for (x, y) in [
+            (4, 0),
+            (9, 1),
+            (2, 3),
+            (7, 4),
+            (1, 6),
+            (8, 7),
+            (0, 8),
+            (6, 9),
+        ] {
+            obstacles.insert(&Position { x, y });
+        }
         assert_eq!(
             TEST_STR.parse(),
             Ok(Map {
@@ -376,16 +390,7 @@ mod tests {
                     position: (4, 6)
                 }),
                 size: (10, 10),
-                obstacles: HashSet::from([
-                    (4, 0),
-                    (9, 1),
-                    (2, 3),
-                    (7, 4),
-                    (1, 6),
-                    (8, 7),
-                    (0, 8),
-                    (6, 9)
-                ]),
+                obstacles,
                 iterations: 0,
             })
         )
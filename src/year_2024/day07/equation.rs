@@ -1,6 +1,7 @@
-use super::checked_op::CheckedOp;
 use super::error::Error;
-use super::operation_selection::*;
+use super::operation_selection::Operation;
+use crate::util::combinatorics::CartesianPower;
+use crate::util::num::CheckedOp;
 
 #[derive(Debug, PartialEq)]
 pub struct Equation<T> {
@@ -44,68 +45,45 @@ impl<T: std::str::FromStr> std::str::FromStr for Equation<T> {
 
 impl<T: CheckedOp + PartialEq + Clone> Equation<T> {
     pub fn is_solvable_add_mul(&self) -> bool {
-        let len = self.test_values.len();
-        if len == 1usize {
-            self.expected == *self.test_values.first().unwrap()
-        } else {
-            for i in 0..1 << (self.test_values.len() - 1) {
-                let should_add_bit_vector = get_bit_vector(i, len - 1).unwrap();
-                let mut iter = self.test_values.iter();
-                let mut val: Option<T> = iter.next().map(|x| x.clone());
-                for (value, perform_addition) in iter.zip(should_add_bit_vector.iter()) {
-                    match val {
-                        None => break,
-                        Some(v) => {
-                            if *perform_addition {
-                                val = v.checked_add(value.clone());
-                            } else {
-                                val = v.checked_mul(value.clone());
-                            }
-                        }
-                    }
-                }
-                if Some(self.expected.clone()) == val {
-                    return true;
-                }
-            }
-            false
-        }
+        self.is_solvable_with(&[Operation::Addition, Operation::Multiplication])
     }
 
     pub fn is_solvable_add_mul_concat(&self) -> bool {
-        let len = self.test_values.len();
-        if len == 1usize {
-            self.expected == *self.test_values.first().unwrap()
-        } else {
-            for i in 0u32..3u32.pow(
-                <usize as std::convert::TryInto<u32>>::try_into(self.test_values.len()).unwrap()
-                    - 1u32,
-            ) {
-                let operation_vector = get_enumeration_vector(i, len - 1).unwrap();
-                let mut iter = self.test_values.iter();
-                let mut val: Option<T> = iter.next().map(|x| x.clone());
-                for (value, operation) in iter.zip(operation_vector.iter()) {
-                    match val {
-                        None => break,
-                        Some(v) => match operation {
-                            Operation::Addition => {
-                                val = v.checked_add(value.clone());
-                            }
-                            Operation::Multiplication => {
-                                val = v.checked_mul(value.clone());
-                            }
-                            Operation::Concatination => {
-                                val = v.concat(value.clone());
-                            }
-                        },
-                    }
-                }
-                if Some(self.expected.clone()) == val {
-                    return true;
+        self.is_solvable_with(&[
+            Operation::Addition,
+            Operation::Multiplication,
+            Operation::Concatination,
+        ])
+    }
+
+    fn is_solvable_with(&self, operations: &[Operation]) -> bool {
+        let Some((first, rest)) = self.test_values.split_first() else {
+            return false;
+        };
+        let mut operation_choices = CartesianPower::new(operations, rest.len());
+        while let Some(chosen_operations) = operation_choices.advance() {
+            let mut val: Option<T> = Some(first.clone());
+            for (value, operation) in rest.iter().zip(chosen_operations) {
+                match val {
+                    None => break,
+                    Some(v) => match operation {
+                        Operation::Addition => {
+                            val = v.checked_add(value.clone());
+                        }
+                        Operation::Multiplication => {
+                            val = v.checked_mul(value.clone());
+                        }
+                        Operation::Concatination => {
+                            val = v.concat(value.clone());
+                        }
+                    },
                 }
             }
-            false
+            if Some(self.expected.clone()) == val {
+                return true;
+            }
         }
+        false
     }
 }
 
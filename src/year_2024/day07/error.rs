@@ -2,8 +2,6 @@
 pub enum Error {
     ParsingFailed,
     IntegerTypeTooSmall,
-    BitFieldGeneration,
-    EnumerationFieldGeneration,
     AccumulationFailed,
 }
 
@@ -14,14 +12,6 @@ impl std::fmt::Display for Error {
             Error::IntegerTypeTooSmall => {
                 write!(f, "failed to parse integer because data type is too small")
             }
-            Error::BitFieldGeneration => write!(
-                f,
-                "failed to generate proper bit field from unsinged integer"
-            ),
-            Error::EnumerationFieldGeneration => write!(
-                f,
-                "failed to generate proper enumeration field from unsinged integer"
-            ),
             Error::AccumulationFailed => write!(f, "result accumulation failed"),
         }
     }
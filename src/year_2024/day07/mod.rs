@@ -1,9 +1,9 @@
 pub mod error;
 
-mod checked_op;
 mod equation;
 mod operation_selection;
 
+use crate::util::bigint::BigUint;
 use equation::Equation;
 use error::Error;
 
@@ -21,18 +21,17 @@ pub fn get_sum_of_calibration_values(input: &str) -> Result<u64, Error> {
         .ok_or(Error::AccumulationFailed)
 }
 
-pub fn get_sum_of_calibration_values_with_concat(input: &str) -> Result<u128, Error> {
-    input
+/// Summed as a big integer, since the test values of all solvable equations may overflow.
+pub fn get_sum_of_calibration_values_with_concat(input: &str) -> Result<BigUint, Error> {
+    Ok(input
         .lines()
         .into_iter()
         .map(|l| l.parse::<Equation<u128>>())
         .collect::<Result<Vec<_>, Error>>()?
         .iter()
         .filter(|e| e.is_solvable_add_mul_concat())
-        .fold(Some(0u128), |acc, i| {
-            acc.and_then(|a| a.checked_add(i.expected))
-        })
-        .ok_or(Error::AccumulationFailed)
+        .map(|e| BigUint::from(e.expected))
+        .sum())
 }
 
 #[cfg(test)]
@@ -58,7 +57,7 @@ mod tests {
     fn sum_results_with_concat() {
         assert_eq!(
             get_sum_of_calibration_values_with_concat(TEST_STR),
-            Ok(11387)
+            Ok(BigUint::from(11387u32))
         );
     }
 }
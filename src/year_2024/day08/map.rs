@@ -2,9 +2,9 @@ use super::antenna::Antenna;
 use super::antinode::Antinode;
 use super::error::Error;
 use super::position_diff::PositionDifference;
+use crate::util::combinatorics::pairs;
 use crate::util::position::Position;
 use crate::util::size::Size;
-use std::collections::hash_set::HashSet;
 
 #[derive(Debug, PartialEq)]
 pub struct Map {
@@ -100,34 +100,13 @@ impl Map {
         }
     }
 
-    fn get_antenna_pairs(&self) -> HashSet<(&Antenna, &Antenna)> {
-        let frequencies = self
-            .antennas
-            .iter()
-            .map(|a| a.frequency)
-            .collect::<HashSet<_>>();
-        let mut antenna_pairs: HashSet<(&Antenna, &Antenna)> = HashSet::new();
-        for frequency in frequencies {
-            let antennas_with_frequency_iter =
-                self.antennas.iter().filter(|a| a.frequency == frequency);
-            for first_antenna in antennas_with_frequency_iter.clone() {
-                for second_antenna in antennas_with_frequency_iter.clone() {
-                    if first_antenna != second_antenna
-                        && !antenna_pairs.contains(&(first_antenna, second_antenna))
-                        && !antenna_pairs.contains(&(&second_antenna, &first_antenna))
-                    {
-                        antenna_pairs.insert((first_antenna, second_antenna));
-                    }
-                }
-            }
-        }
-        antenna_pairs
+    fn get_antenna_pairs(&self) -> impl Iterator<Item = (&Antenna, &Antenna)> {
+        pairs(&self.antennas).filter(|(first, second)| first.frequency == second.frequency)
     }
 
     pub fn get_antinodes_double_distance(&self) -> Vec<Antinode> {
         let mut antinodes: Vec<Antinode> = Vec::new();
-        let antenna_pairs = self.get_antenna_pairs();
-        for (first_antenna, second_antenna) in antenna_pairs.iter() {
+        for (first_antenna, second_antenna) in self.get_antenna_pairs() {
             let (mut add_iter, mut sub_iter) =
                 self.get_antinode_iterators(first_antenna, second_antenna);
             if let Some(antinode) = add_iter.nth(1) {
@@ -184,6 +163,7 @@ impl Iterator for AntinodeIterator {
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
+    use std::collections::HashSet;
 
     use crate::year_2024::day08::tests::TEST_STR;
 
@@ -5,7 +5,6 @@ mod antinode;
 mod map;
 mod position;
 mod position_diff;
-mod signed_diff;
 
 pub fn count_distinct_antinode_positions(input: &str) -> Result<usize, error::Error> {
     let map = input.parse::<map::Map>()?;
@@ -1,5 +1,5 @@
 use super::position_diff::PositionDifference;
-use super::signed_diff::SignedDiff;
+use crate::util::num::SignedDiff;
 use crate::util::position::Position;
 
 impl Position {
@@ -3,6 +3,7 @@ use std::ops::Index;
 use std::str::FromStr;
 
 use crate::util::matrix::Matrix;
+use crate::util::memo::Memo;
 use crate::util::position::Position;
 
 #[derive(Debug, PartialEq)]
@@ -78,6 +79,23 @@ impl Map {
             None => HashSet::new(),
         }
     }
+
+    /// Counts the distinct hiking trails from a position up to a height of 9, caching the
+    /// count of every position on the way.
+    pub fn trail_counter(&self) -> Memo<'_, Position, u64> {
+        Memo::new(|trails, position: Position| {
+            let height = self.matrix[position];
+            if height == 9 {
+                return 1;
+            }
+            position
+                .sourounding_positions()
+                .into_iter()
+                .filter(|x| x.is_within_size(&self.matrix.size()) && height + 1 == self.matrix[x])
+                .map(|x| trails.get(x))
+                .sum()
+        })
+    }
 }
 
 struct TrailIterator<'m> {
@@ -181,6 +199,15 @@ mod tests {
         assert_eq!(expected, input.get_trail_ends(&head))
     }
 
+    #[rstest]
+    #[case("01234\n98765".parse::<Map>().unwrap(), Position { x: 0, y: 0 }, 1)]
+    #[case("0123\n1234\n2345\n3456\n4567\n5678\n6789".parse::<Map>().unwrap(), Position { x: 0, y: 0 }, 84)]
+    #[case(TEST_STR.parse::<Map>().unwrap(), Position { x: 2, y: 0 }, 20)]
+    #[case(TEST_STR.parse::<Map>().unwrap(), Position { x: 6, y: 6 }, 8)]
+    fn count_trails(#[case] input: Map, #[case] head: Position, #[case] expected: u64) {
+        assert_eq!(expected, input.trail_counter().get(head))
+    }
+
     #[rstest]
     #[case(Matrix::<u32>::from_vec(vec![0, 1, 2, 3, 4, 5, 6, 7, 8], 3, 3).unwrap(), &Position{ x: 0, y: 0}, 0)]
     #[case(Matrix::<u32>::from_vec(vec![0, 1, 2, 3, 4, 5, 6, 7, 8], 3, 3).unwrap(), &Position{ x: 1, y: 0}, 1)]
@@ -20,9 +20,18 @@ pub fn sum_trailhead_scores(input: &str) -> Result<u32, Error> {
     Ok(score_sum)
 }
 
+pub fn sum_trailhead_ratings(input: &str) -> Result<u64, Error> {
+    let map = input.parse::<Map>()?;
+    let mut trails = map.trail_counter();
+    map.get_trail_heads()
+        .into_iter()
+        .try_fold(0u64, |acc, head| acc.checked_add(trails.get(*head)))
+        .ok_or(Error::IntegerOverflow)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::sum_trailhead_scores;
+    use super::{sum_trailhead_ratings, sum_trailhead_scores};
 
     pub(super) const TEST_STR: &str = "89010123
 78121874
@@ -37,4 +46,9 @@ mod tests {
     fn sum_scores() {
         assert_eq!(sum_trailhead_scores(TEST_STR), Ok(36));
     }
+
+    #[test]
+    fn sum_ratings() {
+        assert_eq!(sum_trailhead_ratings(TEST_STR), Ok(81));
+    }
 }
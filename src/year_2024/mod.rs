@@ -121,6 +121,10 @@ pub fn solve(day: u8, input: &str) {
                 Ok(x) => println!("part 1: {}", x),
                 Err(e) => println!("{}", e),
             }
+            match day10::sum_trailhead_ratings(input) {
+                Ok(x) => println!("part 2: {}", x),
+                Err(e) => println!("{}", e),
+            }
         }
         11..25 => {
             super::print_not_implemented(2024, day);